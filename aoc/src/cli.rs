@@ -0,0 +1,202 @@
+//! Command line arguments of the `aoc` binary.
+
+use std::path::PathBuf;
+
+use crate::inputs::DEFAULT_NAME;
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run --day <N> [--part <1|2> | --strategy <NAME>] [--input-name <NAME>] [--record]
+    aoc bench --day <N> [--runs <N>] [--input-name <NAME>]
+    aoc inputs [--day <N>]
+    aoc strategies [--day <N>]
+
+Options:
+    --root <DIR>    Directory holding inputs/ and the dayN/ crates [default: .]";
+
+/// Options of the `run` command.
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub day: u32,
+    pub part: Option<u32>,
+    pub strategy: Option<String>,
+    pub input_name: String,
+    pub record: bool,
+}
+
+/// Options of the `bench` command.
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub day: u32,
+    pub input_name: String,
+    pub runs: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    /// Solve a day against one of its inputs.
+    Run(RunOptions),
+    /// Time both parts and every strategy of a day.
+    Bench(BenchOptions),
+    /// List the inputs and their recorded answers.
+    Inputs { day: Option<u32> },
+    /// List the strategies registered for each day.
+    Strategies { day: Option<u32> },
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub root: PathBuf,
+    pub command: Command,
+}
+
+/// Parse the arguments following the program name.
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Args, String> {
+    let mut args = args.into_iter();
+    let command = args.next().unwrap_or_default();
+
+    let mut root = PathBuf::from(".");
+    let mut day = None;
+    let mut part = None;
+    let mut strategy = None;
+    let mut input_name = String::from(DEFAULT_NAME);
+    let mut record = false;
+    let mut runs = 10;
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+        match arg.as_str() {
+            "--root" => root = PathBuf::from(value()?),
+            "--day" => day = Some(parse_number(&value()?, "day")?),
+            "--part" => part = Some(parse_number(&value()?, "part")?),
+            "--strategy" => strategy = Some(value()?),
+            "--runs" => runs = parse_number(&value()?, "runs")?,
+            "--input-name" => input_name = value()?,
+            "--record" => record = true,
+            "-h" | "--help" => return Ok(Args { root, command: Command::Help }),
+            _ => return Err(format!("Unknown argument {}", arg)),
+        }
+    }
+
+    if part.is_some_and(|p| !(1..=2).contains(&p)) {
+        return Err(String::from("Part must be 1 or 2"));
+    }
+
+    if part.is_some() && strategy.is_some() {
+        return Err(String::from("Use either --part or --strategy"));
+    }
+
+    if runs == 0 {
+        return Err(String::from("Runs must be at least 1"));
+    }
+
+    let command = match command.as_str() {
+        "run" => Command::Run(RunOptions {
+            day: day.ok_or("Missing --day")?,
+            part,
+            strategy,
+            input_name,
+            record,
+        }),
+        "bench" => Command::Bench(BenchOptions {
+            day: day.ok_or("Missing --day")?,
+            input_name,
+            runs,
+        }),
+        "inputs" => Command::Inputs { day },
+        "strategies" => Command::Strategies { day },
+        "" | "help" | "-h" | "--help" => Command::Help,
+        _ => return Err(format!("Unknown command {}", command)),
+    };
+
+    Ok(Args { root, command })
+}
+
+fn parse_number(value: &str, name: &str) -> Result<u32, String> {
+    value.parse::<u32>().map_err(|_| format!("Invalid {} '{}'", name, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Args, String> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn given_run_arguments_expect_options() {
+        let args = parse("run --day 5 --input-name alice --part 2 --record").unwrap();
+
+        assert_eq!(args.root, PathBuf::from("."));
+        assert_eq!(
+            args.command,
+            Command::Run(RunOptions {
+                day: 5,
+                part: Some(2),
+                strategy: None,
+                input_name: String::from("alice"),
+                record: true,
+            })
+        );
+    }
+
+    #[test]
+    fn given_run_without_name_expect_default_input() {
+        let args = parse("run --root /tmp/aoc --day 12").unwrap();
+
+        assert_eq!(args.root, PathBuf::from("/tmp/aoc"));
+        assert_eq!(
+            args.command,
+            Command::Run(RunOptions {
+                day: 12,
+                part: None,
+                strategy: None,
+                input_name: String::from(DEFAULT_NAME),
+                record: false,
+            })
+        );
+    }
+
+    #[test]
+    fn given_strategy_expect_it_selected() {
+        let Command::Run(options) = parse("run --day 11 --strategy naive").unwrap().command else {
+            panic!("Expected run command");
+        };
+
+        assert_eq!(options.strategy.as_deref(), Some("naive"));
+        assert!(parse("run --day 11 --strategy naive --part 1").is_err());
+    }
+
+    #[test]
+    fn given_bench_arguments_expect_options() {
+        assert_eq!(
+            parse("bench --day 11 --runs 3").unwrap().command,
+            Command::Bench(BenchOptions {
+                day: 11,
+                input_name: String::from(DEFAULT_NAME),
+                runs: 3,
+            })
+        );
+        assert!(parse("bench --day 11 --runs 0").is_err());
+        assert!(parse("bench").is_err());
+    }
+
+    #[test]
+    fn given_inputs_arguments_expect_filter() {
+        assert_eq!(parse("inputs").unwrap().command, Command::Inputs { day: None });
+        assert_eq!(parse("inputs --day 3").unwrap().command, Command::Inputs { day: Some(3) });
+        assert_eq!(parse("strategies").unwrap().command, Command::Strategies { day: None });
+    }
+
+    #[test]
+    fn given_bad_arguments_expect_error() {
+        assert!(parse("run").is_err());
+        assert!(parse("run --day five").is_err());
+        assert!(parse("run --day 5 --part 3").is_err());
+        assert!(parse("run --day").is_err());
+        assert!(parse("fly --day 5").is_err());
+        assert!(parse("inputs --verbose").is_err());
+    }
+}
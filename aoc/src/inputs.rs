@@ -0,0 +1,239 @@
+//! Named puzzle inputs kept as `inputs/dayNN/<name>.txt`, with the answers recorded for them in
+//! `inputs/dayNN/<name>.answers` using the same `Part N: answer` lines the solutions print.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the input used when none is given. It falls back to the `dayN/input.txt` of the crate.
+pub const DEFAULT_NAME: &str = "input";
+
+/// The answers recorded for an input.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    /// Read `Part N: answer` lines, ignoring anything else.
+    pub fn parse(text: &str) -> Answers {
+        let mut answers = Answers::default();
+        for line in text.lines() {
+            let Some((label, value)) = line.split_once(':') else {
+                continue;
+            };
+
+            match label.trim() {
+                "Part 1" => answers.part_1 = Some(value.trim().to_string()),
+                "Part 2" => answers.part_2 = Some(value.trim().to_string()),
+                _ => (),
+            }
+        }
+        answers
+    }
+
+    /// Answer of a part, `None` when it was never recorded.
+    pub fn get(&self, part: u32) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Record the answer of a part.
+    pub fn set(&mut self, part: u32, answer: &str) {
+        match part {
+            1 => self.part_1 = Some(answer.to_string()),
+            2 => self.part_2 = Some(answer.to_string()),
+            _ => (),
+        }
+    }
+
+    /// Write the answers back as `Part N: answer` lines.
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for part in 1..=2 {
+            if let Some(answer) = self.get(part) {
+                text.push_str(&format!("Part {}: {}\n", part, answer));
+            }
+        }
+        text
+    }
+}
+
+/// A puzzle input found on disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Input {
+    pub day: u32,
+    pub name: String,
+    pub path: PathBuf,
+    pub answers: Answers,
+}
+
+impl Input {
+    /// Read the contents of the input.
+    pub fn read(&self) -> Result<String, String> {
+        fs::read_to_string(&self.path)
+            .map_err(|e| format!("Unable to read {}: {}", self.path.display(), e))
+    }
+}
+
+/// Looks up inputs below a repository root containing `inputs/` and the `dayN/` crates.
+#[derive(Debug)]
+pub struct InputStore {
+    root: PathBuf,
+}
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>) -> InputStore {
+        InputStore { root: root.into() }
+    }
+
+    /// Directory holding the inputs of a day.
+    pub fn day_dir(&self, day: u32) -> PathBuf {
+        self.root.join("inputs").join(format!("day{:02}", day))
+    }
+
+    fn answers_path(&self, day: u32, name: &str) -> PathBuf {
+        self.day_dir(day).join(format!("{}.answers", name))
+    }
+
+    fn legacy_path(&self, day: u32) -> PathBuf {
+        self.root.join(format!("day{}", day)).join("input.txt")
+    }
+
+    fn load(&self, day: u32, name: &str, path: PathBuf) -> Input {
+        let answers = fs::read_to_string(self.answers_path(day, name))
+            .map(|text| Answers::parse(&text))
+            .unwrap_or_default();
+
+        Input { day, name: name.to_string(), path, answers }
+    }
+
+    /// Find an input of a day by name.
+    pub fn find(&self, day: u32, name: &str) -> Result<Input, String> {
+        let path = self.day_dir(day).join(format!("{}.txt", name));
+        if path.is_file() {
+            return Ok(self.load(day, name, path));
+        }
+
+        let legacy = self.legacy_path(day);
+        if name == DEFAULT_NAME && legacy.is_file() {
+            return Ok(self.load(day, name, legacy));
+        }
+
+        Err(format!("No input named '{}' for day {} in {}", name, day, self.day_dir(day).display()))
+    }
+
+    /// Every input of the given day, or of all days, sorted by day and name.
+    pub fn list(&self, day: Option<u32>) -> Vec<Input> {
+        let days = match day {
+            Some(day) => day..=day,
+            None => 1..=25,
+        };
+
+        let mut inputs = Vec::new();
+        for day in days {
+            let mut names = txt_names(&self.day_dir(day));
+            if !names.iter().any(|n| n == DEFAULT_NAME) && self.legacy_path(day).is_file() {
+                names.push(DEFAULT_NAME.to_string());
+            }
+            names.sort();
+
+            inputs.extend(names.iter().filter_map(|n| self.find(day, n).ok()));
+        }
+        inputs
+    }
+
+    /// Store the answers of an input, replacing what was recorded before.
+    pub fn record(&self, input: &Input, answers: &Answers) -> Result<(), String> {
+        let dir = self.day_dir(input.day);
+        fs::create_dir_all(&dir).map_err(|e| format!("Unable to create {}: {}", dir.display(), e))?;
+
+        let path = self.answers_path(input.day, &input.name);
+        fs::write(&path, answers.to_text())
+            .map_err(|e| format!("Unable to write {}: {}", path.display(), e))
+    }
+}
+
+// Names of the `.txt` files in a directory, empty when it does not exist
+fn txt_names(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+        .filter_map(|p| p.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(test: &str) -> InputStore {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-{}", test));
+        let _ = fs::remove_dir_all(&root);
+
+        fs::create_dir_all(root.join("inputs/day05")).unwrap();
+        fs::create_dir_all(root.join("day5")).unwrap();
+        fs::write(root.join("inputs/day05/alice.txt"), "alice").unwrap();
+        fs::write(root.join("inputs/day05/alice.answers"), "Part 1: 143\n").unwrap();
+        fs::write(root.join("inputs/day05/notes.md"), "ignored").unwrap();
+        fs::write(root.join("day5/input.txt"), "official").unwrap();
+
+        InputStore::new(root)
+    }
+
+    #[test]
+    fn given_answers_text_expect_parts() {
+        let answers = Answers::parse("Part 1: 143\nsomething else\nPart 2:  123 \n");
+
+        assert_eq!(answers.get(1), Some("143"));
+        assert_eq!(answers.get(2), Some("123"));
+        assert_eq!(answers.to_text(), "Part 1: 143\nPart 2: 123\n");
+    }
+
+    #[test]
+    fn given_named_input_expect_contents_and_answers() {
+        let store = store("find");
+        let input = store.find(5, "alice").unwrap();
+
+        assert_eq!(input.read().unwrap(), "alice");
+        assert_eq!(input.answers.get(1), Some("143"));
+        assert_eq!(input.answers.get(2), None);
+    }
+
+    #[test]
+    fn given_default_name_expect_crate_input() {
+        let store = store("legacy");
+
+        assert_eq!(store.find(5, DEFAULT_NAME).unwrap().read().unwrap(), "official");
+        assert!(store.find(5, "bob").is_err());
+        assert!(store.find(6, DEFAULT_NAME).is_err());
+    }
+
+    #[test]
+    fn given_inputs_expect_sorted_listing() {
+        let store = store("list");
+        let names: Vec<String> = store.list(None).into_iter().map(|i| i.name).collect();
+
+        assert_eq!(names, vec!["alice", "input"]);
+        assert!(store.list(Some(4)).is_empty());
+    }
+
+    #[test]
+    fn given_recorded_answers_expect_them_found() {
+        let store = store("record");
+        let input = store.find(5, DEFAULT_NAME).unwrap();
+        let mut answers = input.answers.clone();
+        answers.set(2, "123");
+        store.record(&input, &answers).unwrap();
+
+        assert_eq!(store.find(5, DEFAULT_NAME).unwrap().answers.get(2), Some("123"));
+    }
+}
@@ -0,0 +1,205 @@
+//! Runs the solutions of every day against named inputs.
+//!
+//! The solvers only take the input as text. Reading inputs from disk lives behind the `fs`
+//! feature, so building with `--no-default-features --features wasm` gives a core without
+//! filesystem access for `wasm32-unknown-unknown`.
+
+#[cfg(feature = "fs")]
+pub mod cli;
+#[cfg(feature = "fs")]
+pub mod inputs;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/// Takes the puzzle input and returns the answer as text.
+pub type SolveFn = fn(&str) -> String;
+
+/// The two parts of a day's solution.
+pub struct Solver {
+    pub day: u32,
+    pub part_1: SolveFn,
+    pub part_2: SolveFn,
+    pub strategies: &'static [Strategy],
+}
+
+impl Solver {
+    /// Find a strategy of the day by name.
+    pub fn strategy(&self, name: &str) -> Option<&'static Strategy> {
+        self.strategies.iter().find(|s| s.name == name)
+    }
+}
+
+/// A named algorithm answering one part of a day, so alternatives can be run side by side.
+pub struct Strategy {
+    pub name: &'static str,
+    pub part: u32,
+    pub description: &'static str,
+    pub solve: SolveFn,
+}
+
+macro_rules! solver {
+    ($day:expr, $krate:ident) => {
+        solver!($day, $krate, &[])
+    };
+    ($day:expr, $krate:ident, $strategies:expr) => {
+        Solver {
+            day: $day,
+            part_1: |input| $krate::part_1(input).to_string(),
+            part_2: |input| $krate::part_2(input).to_string(),
+            strategies: $strategies,
+        }
+    };
+}
+
+const DAY11_STRATEGIES: [Strategy; 2] = [
+    Strategy {
+        name: "grouped",
+        part: 1,
+        description: "Count stones grouped by their value",
+        solve: |input| day11::part_1(input).to_string(),
+    },
+    Strategy {
+        name: "naive",
+        part: 1,
+        description: "Keep every stone in a list, too slow for part 2",
+        solve: |input| day11::solve_naive(&day11::read_stones(input), 25).to_string(),
+    },
+];
+
+const DAY18_STRATEGIES: [Strategy; 2] = [
+    Strategy {
+        name: "binary-search",
+        part: 2,
+        description: "Binary search the number of fallen bytes, flood filling each grid",
+        solve: day18::part_2,
+    },
+    Strategy {
+        name: "union-find",
+        part: 2,
+        description: "Remove bytes in reverse, joining freed positions until the exit connects",
+        solve: day18::part_2_union_find,
+    },
+];
+
+/// Solvers of every implemented day, in order.
+pub const SOLVERS: [Solver; 25] = [
+    solver!(1, day1),
+    solver!(2, day2),
+    solver!(3, day3),
+    solver!(4, day4),
+    solver!(5, day5),
+    solver!(6, day6),
+    solver!(7, day7),
+    solver!(8, day8),
+    solver!(9, day9),
+    solver!(10, day10),
+    solver!(11, day11, &DAY11_STRATEGIES),
+    solver!(12, day12),
+    solver!(13, day13),
+    Solver {
+        day: 14,
+        part_1: |input| day14::part_1(input).to_string(),
+        part_2: |input| match day14::part_2(input) {
+            Some(ticks) => ticks.to_string(),
+            None => String::from("none"),
+        },
+        strategies: &[],
+    },
+    solver!(15, day15),
+    solver!(16, day16),
+    Solver {
+        day: 17,
+        part_1: day17::part_1,
+        part_2: |input| match day17::part_2(input) {
+            Some(a) => a.to_string(),
+            None => String::from("none"),
+        },
+        strategies: &[],
+    },
+    solver!(18, day18, &DAY18_STRATEGIES),
+    solver!(19, day19),
+    solver!(20, day20),
+    solver!(21, day21),
+    solver!(22, day22),
+    solver!(23, day23),
+    solver!(24, day24),
+    Solver {
+        day: 25,
+        part_1: |input| day25::part_1(input).to_string(),
+        part_2: |_| String::from("none"),
+        strategies: &[],
+    },
+];
+
+/// The solver of a day, `None` when the day is not implemented.
+pub fn solver(day: u32) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day)
+}
+
+/// Solve one part of a day, `None` when the day or part does not exist.
+pub fn solve(day: u32, part: u32, input: &str) -> Option<String> {
+    let solver = solver(day)?;
+    match part {
+        1 => Some((solver.part_1)(input)),
+        2 => Some((solver.part_2)(input)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_day_and_part_expect_answer() {
+        assert_eq!(solve(1, 1, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap(), "11");
+        assert_eq!(solve(11, 1, "125 17").unwrap(), "55312");
+    }
+
+    #[test]
+    fn given_unknown_day_or_part_expect_none() {
+        assert!(solve(26, 1, "").is_none());
+        assert!(solve(1, 3, "").is_none());
+    }
+
+    #[test]
+    fn given_strategies_expect_same_answers_as_parts() {
+        let samples = [(11, "125 17")];
+
+        for (day, input) in samples {
+            for strategy in solver(day).unwrap().strategies {
+                assert_eq!(
+                    (strategy.solve)(input),
+                    solve(day, strategy.part, input).unwrap(),
+                    "day {} strategy {}",
+                    day,
+                    strategy.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn given_day11_sample_expect_strategies_side_by_side() {
+        let day11 = solver(11).unwrap();
+
+        assert_eq!((day11.strategy("grouped").unwrap().solve)("125 17"), "55312");
+        assert_eq!((day11.strategy("naive").unwrap().solve)("125 17"), "55312");
+        assert!(day11.strategy("missing").is_none());
+    }
+
+    #[test]
+    fn strategies_have_unique_names_and_valid_parts() {
+        for solver in SOLVERS.iter() {
+            for (i, strategy) in solver.strategies.iter().enumerate() {
+                assert!((1..=2).contains(&strategy.part));
+                assert!(solver.strategies[i + 1..].iter().all(|s| s.name != strategy.name));
+            }
+        }
+    }
+
+    #[test]
+    fn solvers_are_in_day_order() {
+        assert!(SOLVERS.iter().enumerate().all(|(i, s)| s.day == i as u32 + 1));
+    }
+}
@@ -0,0 +1,155 @@
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc::cli::{self, BenchOptions, Command, RunOptions};
+use aoc::inputs::InputStore;
+use aoc::{SolveFn, Solver};
+
+fn find_solver(day: u32) -> Result<&'static Solver, String> {
+    aoc::solver(day).ok_or(format!("Day {} is not solved", day))
+}
+
+// The parts to run, each with its label and solve function
+fn selected(solver: &'static Solver, options: &RunOptions) -> Result<Vec<(u32, String, SolveFn)>, String> {
+    if let Some(name) = &options.strategy {
+        let strategy = solver
+            .strategy(name)
+            .ok_or(format!("Day {} has no strategy named '{}'", solver.day, name))?;
+        let label = format!("Part {} ({})", strategy.part, strategy.name);
+        return Ok(vec![(strategy.part, label, strategy.solve)]);
+    }
+
+    let mut parts = Vec::new();
+    if options.part.is_none_or(|p| p == 1) {
+        parts.push((1, String::from("Part 1"), solver.part_1));
+    }
+    if options.part.is_none_or(|p| p == 2) {
+        parts.push((2, String::from("Part 2"), solver.part_2));
+    }
+    Ok(parts)
+}
+
+fn run(store: &InputStore, options: &RunOptions) -> Result<bool, String> {
+    let solver = find_solver(options.day)?;
+    let input = store.find(options.day, &options.input_name)?;
+    let contents = input.read()?;
+
+    let mut answers = input.answers.clone();
+    let mut matches = true;
+    for (part, label, solve) in selected(solver, options)? {
+        let answer = solve(&contents);
+
+        match input.answers.get(part) {
+            Some(recorded) if recorded != answer => {
+                println!("{}: {} (recorded: {})", label, answer, recorded);
+                matches = false;
+            }
+            _ => println!("{}: {}", label, answer),
+        }
+        answers.set(part, &answer);
+    }
+
+    if options.record {
+        store.record(&input, &answers)?;
+    }
+
+    Ok(matches || options.record)
+}
+
+fn bench(store: &InputStore, options: &BenchOptions) -> Result<(), String> {
+    let solver = find_solver(options.day)?;
+    let input = store.find(options.day, &options.input_name)?;
+    let contents = input.read()?;
+
+    let mut candidates: Vec<(u32, &str, SolveFn)> =
+        vec![(1, "part 1", solver.part_1), (2, "part 2", solver.part_2)];
+    candidates.extend(solver.strategies.iter().map(|s| (s.part, s.name, s.solve)));
+
+    for (part, name, solve) in candidates {
+        let mut times = Vec::new();
+        let mut answer = String::new();
+        for _ in 0..options.runs {
+            let start = Instant::now();
+            answer = solve(&contents);
+            times.push(start.elapsed());
+        }
+
+        let min = times.iter().min().copied().unwrap_or_default();
+        let mean = times.iter().sum::<Duration>() / options.runs;
+        let check = match input.answers.get(part) {
+            Some(recorded) if recorded != answer => format!(" (recorded: {})", recorded),
+            _ => String::new(),
+        };
+
+        println!(
+            "Part {}  {:<16}  min {:>12.3?}  mean {:>12.3?}  {}{}",
+            part, name, min, mean, answer, check
+        );
+    }
+
+    Ok(())
+}
+
+fn list_strategies(day: Option<u32>) {
+    for solver in aoc::SOLVERS.iter().filter(|s| day.is_none_or(|d| d == s.day)) {
+        for strategy in solver.strategies {
+            println!(
+                "day{:02}  Part {}  {:<16}  {}",
+                solver.day, strategy.part, strategy.name, strategy.description
+            );
+        }
+    }
+}
+
+fn list(store: &InputStore, day: Option<u32>) {
+    for input in store.list(day) {
+        println!(
+            "day{:02}  {:<16}  Part 1: {:<20}  Part 2: {}",
+            input.day,
+            input.name,
+            input.answers.get(1).unwrap_or("-"),
+            input.answers.get(2).unwrap_or("-")
+        );
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let store = InputStore::new(args.root);
+    match args.command {
+        Command::Run(options) => match run(&store, &options) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Bench(options) => match bench(&store, &options) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("{}", e);
+                ExitCode::FAILURE
+            }
+        },
+        Command::Inputs { day } => {
+            list(&store, day);
+            ExitCode::SUCCESS
+        }
+        Command::Strategies { day } => {
+            list_strategies(day);
+            ExitCode::SUCCESS
+        }
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            ExitCode::SUCCESS
+        }
+    }
+}
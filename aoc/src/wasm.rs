@@ -0,0 +1,174 @@
+//! `extern "C"` entry points for embedding the solvers, string in and string out.
+//!
+//! The host copies the UTF-8 puzzle input into memory from [`aoc_alloc`], calls [`aoc_solve`]
+//! or one of the `dayN_solve` functions with a place for the answer, and checks the returned
+//! status. On [`AOC_OK`] it reads the NUL terminated answer and hands it back to
+//! [`aoc_free_answer`]. The input buffer is released with [`aoc_dealloc`].
+//!
+//! Most solvers still panic on a malformed input. `wasm32-unknown-unknown` builds abort on a
+//! panic, so there a malformed input traps the whole instance and the host gets no status. Only
+//! native builds, which unwind, turn a panic into [`AOC_PANICKED`].
+
+use std::ffi::{c_char, CString};
+use std::panic::catch_unwind;
+
+/// The answer was written.
+pub const AOC_OK: i32 = 0;
+/// The day or part does not exist.
+pub const AOC_UNKNOWN: i32 = 1;
+/// The input is not UTF-8.
+pub const AOC_INVALID_INPUT: i32 = 2;
+/// The solver panicked, usually on a malformed input. Only returned by native builds, on
+/// `wasm32-unknown-unknown` the panic traps instead.
+pub const AOC_PANICKED: i32 = 3;
+
+/// Reserve `len` bytes for the host to write an input into.
+#[no_mangle]
+pub extern "C" fn aoc_alloc(len: usize) -> *mut u8 {
+    // A boxed slice is exactly `len` long, so it can be rebuilt from the pointer and length alone
+    let buf = vec![0u8; len].into_boxed_slice();
+    Box::into_raw(buf) as *mut u8
+}
+
+/// Release a buffer from [`aoc_alloc`].
+///
+/// # Safety
+/// `ptr` and `len` must come from a single call to [`aoc_alloc`] and not be released twice.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_dealloc(ptr: *mut u8, len: usize) {
+    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len)));
+}
+
+/// Solve a part of a day and return a status. On [`AOC_OK`] `answer` is set to an answer to
+/// release with [`aoc_free_answer`], otherwise it is set to null.
+///
+/// # Safety
+/// `input` must point to `len` readable bytes and `answer` to a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(day: u32, part: u32, input: *const u8, len: usize, answer: *mut *mut c_char) -> i32 {
+    *answer = std::ptr::null_mut();
+
+    let bytes = std::slice::from_raw_parts(input, len);
+    let Ok(input) = std::str::from_utf8(bytes) else {
+        return AOC_INVALID_INPUT;
+    };
+
+    // Unwinding out of an `extern "C"` function is undefined behaviour. With panic=abort, as on
+    // wasm32, there is nothing to catch and the instance traps
+    match catch_unwind(|| crate::solve(day, part, input)) {
+        Ok(Some(text)) => match CString::new(text) {
+            Ok(text) => {
+                *answer = text.into_raw();
+                AOC_OK
+            }
+            Err(_) => AOC_INVALID_INPUT,
+        },
+        Ok(None) => AOC_UNKNOWN,
+        Err(_) => AOC_PANICKED,
+    }
+}
+
+/// Release an answer set by one of the solve functions.
+///
+/// # Safety
+/// `answer` must come from a solve function and not be released twice. Null is ignored.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_answer(answer: *mut c_char) {
+    if !answer.is_null() {
+        drop(CString::from_raw(answer));
+    }
+}
+
+macro_rules! day_entry {
+    ($name:ident, $day:expr) => {
+        /// Solve a part of this day, see [`aoc_solve`].
+        ///
+        /// # Safety
+        /// `input` must point to `len` readable bytes and `answer` to a writable pointer.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(part: u32, input: *const u8, len: usize, answer: *mut *mut c_char) -> i32 {
+            aoc_solve($day, part, input, len, answer)
+        }
+    };
+}
+
+day_entry!(day1_solve, 1);
+day_entry!(day2_solve, 2);
+day_entry!(day3_solve, 3);
+day_entry!(day4_solve, 4);
+day_entry!(day5_solve, 5);
+day_entry!(day6_solve, 6);
+day_entry!(day7_solve, 7);
+day_entry!(day8_solve, 8);
+day_entry!(day9_solve, 9);
+day_entry!(day10_solve, 10);
+day_entry!(day11_solve, 11);
+day_entry!(day12_solve, 12);
+day_entry!(day13_solve, 13);
+day_entry!(day14_solve, 14);
+day_entry!(day15_solve, 15);
+day_entry!(day16_solve, 16);
+day_entry!(day17_solve, 17);
+day_entry!(day18_solve, 18);
+day_entry!(day19_solve, 19);
+day_entry!(day20_solve, 20);
+day_entry!(day21_solve, 21);
+day_entry!(day22_solve, 22);
+day_entry!(day23_solve, 23);
+day_entry!(day24_solve, 24);
+day_entry!(day25_solve, 25);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    type Entry = unsafe extern "C" fn(u32, *const u8, usize, *mut *mut c_char) -> i32;
+
+    // Copy the input into host memory the way an embedder would and read back the answer
+    fn call(solve: impl Fn(*const u8, usize, *mut *mut c_char) -> i32, input: &[u8]) -> Result<String, i32> {
+        unsafe {
+            let ptr = aoc_alloc(input.len());
+            std::ptr::copy_nonoverlapping(input.as_ptr(), ptr, input.len());
+
+            let mut answer = std::ptr::null_mut();
+            let status = solve(ptr, input.len(), &mut answer);
+            aoc_dealloc(ptr, input.len());
+            if status != AOC_OK {
+                assert!(answer.is_null());
+                return Err(status);
+            }
+
+            let text = CStr::from_ptr(answer).to_str().unwrap().to_string();
+            aoc_free_answer(answer);
+            Ok(text)
+        }
+    }
+
+    #[test]
+    fn given_input_expect_answer_from_both_entry_points() {
+        let sample = b"125 17";
+        let day11: Entry = day11_solve;
+
+        assert_eq!(call(|p, l, a| unsafe { aoc_solve(11, 1, p, l, a) }, sample), Ok(String::from("55312")));
+        assert_eq!(call(|p, l, a| unsafe { day11(1, p, l, a) }, sample), Ok(String::from("55312")));
+    }
+
+    #[test]
+    fn given_unknown_part_or_bad_input_expect_status() {
+        assert_eq!(call(|p, l, a| unsafe { aoc_solve(11, 3, p, l, a) }, b"125 17"), Err(AOC_UNKNOWN));
+        assert_eq!(call(|p, l, a| unsafe { aoc_solve(30, 1, p, l, a) }, b"125 17"), Err(AOC_UNKNOWN));
+        assert_eq!(call(|p, l, a| unsafe { aoc_solve(11, 1, p, l, a) }, &[0xff, 0xfe]), Err(AOC_INVALID_INPUT));
+    }
+
+    // Tests run natively, where panics unwind
+    #[test]
+    fn given_solver_panic_expect_status_instead_of_unwinding() {
+        assert_eq!(call(|p, l, a| unsafe { aoc_solve(11, 1, p, l, a) }, b"not stones"), Err(AOC_PANICKED));
+    }
+
+    #[test]
+    fn given_empty_input_expect_buffer_round_trip() {
+        assert_eq!(call(|p, l, a| unsafe { aoc_solve(11, 1, p, l, a) }, b""), Ok(String::from("0")));
+    }
+}
@@ -0,0 +1,499 @@
+//! Totals of lists too large to sort in memory, using an external merge sort.
+//!
+//! The input is read a run of rows at a time. Each run of both columns is sorted and written to
+//! a [`RunStore`], then the runs of a column are merged back into one sorted stream, at most
+//! `fan_in` runs at a time. The store decides where runs live, so this module does no I/O itself.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::Display;
+use std::io::{BufRead, Cursor, Lines, Write};
+use std::iter::Peekable;
+
+use crate::{distance, parse_row, row_text, LocationId, Total};
+
+/// Rows sorted in memory before a run is written.
+pub const DEFAULT_RUN_SIZE: usize = 1 << 20;
+
+/// Runs read at once by a merge.
+pub const DEFAULT_FAN_IN: usize = 64;
+
+/// Somewhere to keep sorted runs, one value per line, such as temporary files.
+pub trait RunStore {
+    type Writer: Write;
+    type Reader: BufRead;
+
+    /// Start a new run.
+    fn create(&mut self) -> Result<(usize, Self::Writer), String>;
+    /// Keep a run once all of it is written.
+    fn finish(&mut self, run: usize, writer: Self::Writer) -> Result<(), String>;
+    /// Read a finished run from the start.
+    fn open(&self, run: usize) -> Result<Self::Reader, String>;
+    /// Drop a run that is no longer needed.
+    fn remove(&mut self, run: usize);
+}
+
+/// Runs kept in memory.
+#[derive(Debug, Default)]
+pub struct MemoryRuns {
+    runs: Vec<Vec<u8>>,
+}
+
+impl RunStore for MemoryRuns {
+    type Writer = Vec<u8>;
+    type Reader = Cursor<Vec<u8>>;
+
+    fn create(&mut self) -> Result<(usize, Vec<u8>), String> {
+        self.runs.push(Vec::new());
+        Ok((self.runs.len() - 1, Vec::new()))
+    }
+
+    fn finish(&mut self, run: usize, writer: Vec<u8>) -> Result<(), String> {
+        self.runs[run] = writer;
+        Ok(())
+    }
+
+    fn open(&self, run: usize) -> Result<Cursor<Vec<u8>>, String> {
+        Ok(Cursor::new(self.runs[run].clone()))
+    }
+
+    fn remove(&mut self, run: usize) {
+        self.runs[run] = Vec::new();
+    }
+}
+
+/// K-way merge of sorted runs, yielding the smallest next value of any run.
+pub struct Merge<T, R> {
+    runs: Vec<Lines<R>>,
+    heap: BinaryHeap<Reverse<(T, usize)>>,
+}
+
+impl<T: LocationId + Ord, R: BufRead> Merge<T, R> {
+    /// Open the runs of a store for merging.
+    pub fn open<S: RunStore<Reader = R>>(store: &S, runs: &[usize]) -> Result<Self, String> {
+        let mut merge = Merge { runs: Vec::new(), heap: BinaryHeap::new() };
+        for run in runs {
+            merge.runs.push(store.open(*run)?.lines());
+            merge.refill(merge.runs.len() - 1)?;
+        }
+        Ok(merge)
+    }
+
+    // Queue the next value of a run, if it has one left
+    fn refill(&mut self, run: usize) -> Result<(), String> {
+        if let Some(line) = self.runs[run].next() {
+            let line = line.map_err(|e| format!("Unable to read run: {}", e))?;
+            let value = line.parse::<T>().map_err(|_| format!("Corrupt run value '{}'", line))?;
+            self.heap.push(Reverse((value, run)));
+        }
+        Ok(())
+    }
+}
+
+impl<T: LocationId + Ord, R: BufRead> Iterator for Merge<T, R> {
+    type Item = Result<T, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((value, run)) = self.heap.pop()?;
+        Some(self.refill(run).map(|_| value))
+    }
+}
+
+/// Total distance between two sorted streams, pairing them by position.
+pub fn sorted_distance<T, A, I>(left: I, right: I) -> Result<A, String>
+where
+    T: LocationId,
+    A: Total<T>,
+    I: Iterator<Item = Result<T, String>>,
+{
+    let mut total = A::from_count(0);
+    for (l, r) in left.zip(right) {
+        total = total + distance(l?, r?);
+    }
+    Ok(total)
+}
+
+// The next value of a sorted stream and how many times it repeats
+fn next_group<T, I>(iter: &mut Peekable<I>) -> Result<Option<(T, usize)>, String>
+where
+    T: LocationId,
+    I: Iterator<Item = Result<T, String>>,
+{
+    let Some(value) = iter.next() else {
+        return Ok(None);
+    };
+    let value = value?;
+
+    let mut count = 1;
+    while iter.next_if(|next| next.as_ref().is_ok_and(|n| *n == value)).is_some() {
+        count += 1;
+    }
+    Ok(Some((value, count)))
+}
+
+// How many times `value` repeats at the head of a stream, moving on to the next group if it does
+fn take_group<T, I>(group: &mut Option<(T, usize)>, iter: &mut Peekable<I>, value: T) -> Result<usize, String>
+where
+    T: LocationId,
+    I: Iterator<Item = Result<T, String>>,
+{
+    match *group {
+        Some((v, count)) if v == value => {
+            *group = next_group(iter)?;
+            Ok(count)
+        }
+        _ => Ok(0),
+    }
+}
+
+/// Similarity score of two sorted streams, matching runs of equal values instead of counting
+/// every value in a map.
+pub fn sorted_similarity<T, A, I>(left: I, right: I) -> Result<A, String>
+where
+    T: LocationId + Ord,
+    A: Total<T>,
+    I: Iterator<Item = Result<T, String>>,
+{
+    let (mut left, mut right) = (left.peekable(), right.peekable());
+    let mut total = A::from_count(0);
+    let mut right_group = next_group(&mut right)?;
+
+    while let Some((value, left_count)) = next_group(&mut left)? {
+        while right_group.is_some_and(|(v, _)| v < value) {
+            right_group = next_group(&mut right)?;
+        }
+
+        match right_group {
+            Some((v, right_count)) if v == value => {
+                total = total + A::from_id(value) * A::from_count(left_count) * A::from_count(right_count);
+            }
+            Some(_) => (),
+            None => break,
+        }
+    }
+
+    Ok(total)
+}
+
+/// Total distance and similarity score of two sorted streams of the same length, reading each
+/// once.
+// Walking both streams in value order matches equal values for the score. Pairing by position,
+// the distance is the area between the counting functions of the two streams: every step to the
+// next value adds the step times how far one stream's count is ahead of the other's.
+pub fn sorted_totals<T, A, I>(left: I, right: I) -> Result<(A, A), String>
+where
+    T: LocationId + Ord,
+    A: Total<T>,
+    I: Iterator<Item = Result<T, String>>,
+{
+    let (mut left, mut right) = (left.peekable(), right.peekable());
+    let (mut l, mut r) = (next_group(&mut left)?, next_group(&mut right)?);
+    let (mut distance, mut similarity) = (A::from_count(0), A::from_count(0));
+    let mut ahead: i64 = 0;
+    let mut previous = None;
+
+    while let Some(value) = [l, r].into_iter().flatten().map(|(v, _)| v).min() {
+        if let Some(p) = previous {
+            distance = distance + (A::from_id(value) - A::from_id(p)) * A::from_count(ahead.unsigned_abs() as usize);
+        }
+
+        let left_count = take_group(&mut l, &mut left, value)?;
+        let right_count = take_group(&mut r, &mut right, value)?;
+
+        similarity = similarity + A::from_id(value) * A::from_count(left_count) * A::from_count(right_count);
+        ahead += left_count as i64 - right_count as i64;
+        previous = Some(value);
+    }
+
+    if ahead != 0 {
+        return Err(String::from("Sorted streams differ in length"));
+    }
+    Ok((distance, similarity))
+}
+
+/// Sorts the two columns of an input through a run store and totals them.
+pub struct ExternalSort<S> {
+    store: S,
+    run_size: usize,
+    fan_in: usize,
+    skip_comments: bool,
+}
+
+impl<S: RunStore> ExternalSort<S> {
+    pub fn new(store: S) -> Self {
+        ExternalSort { store, run_size: DEFAULT_RUN_SIZE, fan_in: DEFAULT_FAN_IN, skip_comments: false }
+    }
+
+    /// Rows sorted in memory before a run is written.
+    pub fn run_size(mut self, run_size: usize) -> Self {
+        self.run_size = run_size.max(1);
+        self
+    }
+
+    /// Most runs read at once. More runs are merged into fewer ones first.
+    pub fn fan_in(mut self, fan_in: usize) -> Self {
+        self.fan_in = fan_in.max(2);
+        self
+    }
+
+    /// Also skip lines starting with `#`, like [`crate::Pairs::skip_comments`].
+    pub fn skip_comments(mut self) -> Self {
+        self.skip_comments = true;
+        self
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    // Sort the values into a new run and clear them
+    fn write_run<T: LocationId + Ord + Display>(&mut self, values: &mut Vec<T>) -> Result<usize, String> {
+        values.sort();
+        let (run, mut writer) = self.store.create()?;
+        for v in values.iter() {
+            writeln!(writer, "{}", v).map_err(|e| format!("Unable to write run: {}", e))?;
+        }
+        self.store.finish(run, writer)?;
+
+        values.clear();
+        Ok(run)
+    }
+
+    /// Read the two columns into sorted runs of at most `run_size` rows each.
+    pub fn spill<T, R>(&mut self, reader: R) -> Result<(Vec<usize>, Vec<usize>), String>
+    where
+        T: LocationId + Ord + Display,
+        R: BufRead,
+    {
+        let (mut left_runs, mut right_runs) = (Vec::new(), Vec::new());
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| format!("Line {}: {}", i + 1, e))?;
+            let Some(line) = row_text(&line, self.skip_comments) else {
+                continue;
+            };
+
+            let row: Vec<T> = parse_row(line, 2).map_err(|e| format!("Line {}: {}", i + 1, e))?;
+            left.push(row[0]);
+            right.push(row[1]);
+
+            if left.len() >= self.run_size {
+                left_runs.push(self.write_run(&mut left)?);
+                right_runs.push(self.write_run(&mut right)?);
+            }
+        }
+
+        if !left.is_empty() {
+            left_runs.push(self.write_run(&mut left)?);
+            right_runs.push(self.write_run(&mut right)?);
+        }
+
+        Ok((left_runs, right_runs))
+    }
+
+    /// Merge runs `fan_in` at a time until at most `fan_in` are left.
+    pub fn reduce<T: LocationId + Ord + Display>(&mut self, mut runs: Vec<usize>) -> Result<Vec<usize>, String> {
+        while runs.len() > self.fan_in {
+            let mut merged = Vec::new();
+            for group in runs.chunks(self.fan_in) {
+                let (run, mut writer) = self.store.create()?;
+                for v in Merge::<T, S::Reader>::open(&self.store, group)? {
+                    writeln!(writer, "{}", v?).map_err(|e| format!("Unable to write run: {}", e))?;
+                }
+                self.store.finish(run, writer)?;
+
+                for r in group {
+                    self.store.remove(*r);
+                }
+                merged.push(run);
+            }
+            runs = merged;
+        }
+        Ok(runs)
+    }
+
+    /// Total distance and similarity of the two columns read from `reader`, keeping at most
+    /// `run_size` rows in memory. Every run is removed from the store afterwards.
+    pub fn totals<T, A, R>(&mut self, reader: R) -> Result<(A, A), String>
+    where
+        T: LocationId + Ord + Display,
+        A: Total<T>,
+        R: BufRead,
+    {
+        let (left, right) = self.spill::<T, R>(reader)?;
+        let left = self.reduce::<T>(left)?;
+        let right = self.reduce::<T>(right)?;
+
+        let totals = self.merge_totals(&left, &right);
+        for run in left.iter().chain(right.iter()) {
+            self.store.remove(*run);
+        }
+        totals
+    }
+
+    fn merge_totals<T, A>(&self, left: &[usize], right: &[usize]) -> Result<(A, A), String>
+    where
+        T: LocationId + Ord,
+        A: Total<T>,
+    {
+        let merge = |runs: &[usize]| Merge::<T, S::Reader>::open(&self.store, runs);
+        sorted_totals(merge(left)?, merge(right)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_lists, sum_distances, sum_similarity};
+    use std::cell::Cell;
+    use std::io::{BufRead, Read};
+    use std::rc::Rc;
+
+    fn totals(input: &str, run_size: usize) -> Result<(i64, i64), String> {
+        ExternalSort::new(MemoryRuns::default()).run_size(run_size).totals::<i32, i64, _>(input.as_bytes())
+    }
+
+    // Memory runs that track how many readers are open at once
+    #[derive(Default)]
+    struct CountingRuns {
+        runs: MemoryRuns,
+        open: Rc<Cell<usize>>,
+        peak: Rc<Cell<usize>>,
+        opened: Cell<usize>,
+    }
+
+    struct CountingReader {
+        inner: Cursor<Vec<u8>>,
+        open: Rc<Cell<usize>>,
+    }
+
+    impl Read for CountingReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.inner.read(buf)
+        }
+    }
+
+    impl BufRead for CountingReader {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            self.inner.fill_buf()
+        }
+
+        fn consume(&mut self, amount: usize) {
+            self.inner.consume(amount)
+        }
+    }
+
+    impl Drop for CountingReader {
+        fn drop(&mut self) {
+            self.open.set(self.open.get() - 1);
+        }
+    }
+
+    impl RunStore for CountingRuns {
+        type Writer = Vec<u8>;
+        type Reader = CountingReader;
+
+        fn create(&mut self) -> Result<(usize, Vec<u8>), String> {
+            self.runs.create()
+        }
+
+        fn finish(&mut self, run: usize, writer: Vec<u8>) -> Result<(), String> {
+            self.runs.finish(run, writer)
+        }
+
+        fn open(&self, run: usize) -> Result<CountingReader, String> {
+            self.opened.set(self.opened.get() + 1);
+            self.open.set(self.open.get() + 1);
+            self.peak.set(self.peak.get().max(self.open.get()));
+            Ok(CountingReader { inner: self.runs.open(run)?, open: self.open.clone() })
+        }
+
+        fn remove(&mut self, run: usize) {
+            self.runs.remove(run)
+        }
+    }
+
+    fn random_input(rows: usize) -> String {
+        let mut seed: u32 = 42;
+        let mut input = String::new();
+        for _ in 0..rows {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let l = (seed >> 8) % 300;
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let r = (seed >> 8) % 300;
+            input.push_str(&format!("{}   {}\n", l, r));
+        }
+        input
+    }
+
+    #[test]
+    fn given_sample_expect_same_totals_for_any_run_size() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+        for run_size in [1, 2, 4, 6, 100] {
+            assert_eq!(totals(input, run_size), Ok((11, 31)), "run size {}", run_size);
+        }
+    }
+
+    #[test]
+    fn given_many_rows_expect_totals_match_in_memory() {
+        let input = random_input(2000);
+
+        let (mut left, mut right) = read_lists::<i32>(&input).unwrap();
+        let similarity: i64 = sum_similarity(&left, &right);
+        left.sort();
+        right.sort();
+        let distance: i64 = sum_distances(&left, &right);
+
+        assert_eq!(totals(&input, 97), Ok((distance, similarity)));
+    }
+
+    #[test]
+    fn given_many_runs_expect_bounded_open_readers() {
+        let input = random_input(500);
+        let mut sort = ExternalSort::new(CountingRuns::default()).run_size(7).fan_in(4);
+        let expected = totals(&input, 1000);
+
+        assert_eq!(sort.totals::<i32, i64, _>(input.as_bytes()), expected);
+        // Both columns are merged side by side at the end
+        assert!(sort.store().peak.get() <= 2 * 4, "{} readers open", sort.store().peak.get());
+        assert_eq!(sort.store().open.get(), 0);
+        assert!(sort.store().runs.runs.iter().all(|r| r.is_empty()));
+    }
+
+    #[test]
+    fn given_runs_expect_each_read_once() {
+        let input = random_input(500);
+        let mut sort = ExternalSort::new(CountingRuns::default()).run_size(100);
+        let expected = totals(&input, 1000);
+
+        // Five runs of each column, all within the fan-in
+        assert_eq!(sort.totals::<i32, i64, _>(input.as_bytes()), expected);
+        assert_eq!(sort.store().opened.get(), 10);
+    }
+
+    #[test]
+    fn given_sorted_streams_expect_totals_in_one_pass() {
+        let stream = |values: &[i32]| values.iter().map(|v| Ok(*v)).collect::<Vec<_>>().into_iter();
+        let (left, right) = ([1, 2, 3, 3, 3, 4], [3, 3, 3, 4, 5, 9]);
+
+        assert_eq!(sorted_totals::<i32, i64, _>(stream(&left), stream(&right)), Ok((11, 31)));
+        assert_eq!(sorted_totals::<i32, i64, _>(stream(&[]), stream(&[])), Ok((0, 0)));
+        assert!(sorted_totals::<i32, i64, _>(stream(&[1, 2]), stream(&[1])).is_err());
+    }
+
+    #[test]
+    fn given_comments_expect_same_rows_as_pairs() {
+        let input = "# left right\n3   4\n4   3\n\n2   5\n";
+        let sort = || ExternalSort::new(MemoryRuns::default()).run_size(2);
+
+        assert_eq!(sort().totals::<i32, i64, _>(input.as_bytes()), read_lists::<i32>(input).map(|_| (0, 0)));
+        assert_eq!(sort().skip_comments().totals::<i32, i64, _>(input.as_bytes()), Ok((3, 7)));
+    }
+
+    #[test]
+    fn given_malformed_row_expect_line_numbered_error() {
+        assert_eq!(totals("1 2\n3\n", 1), Err(String::from("Line 2: expected 2 columns, found 1")));
+    }
+}
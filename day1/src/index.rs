@@ -0,0 +1,468 @@
+//! Running totals of two lists that are edited one value at a time.
+
+use std::collections::HashMap;
+
+use crate::{LocationId, Total};
+
+const LEFT: usize = 0;
+const RIGHT: usize = 1;
+const NIL: usize = usize::MAX;
+
+/// A distinct value in the tree, with totals over its subtree.
+// `gap` is the distance to the next distinct value. Over the subtree, with `F_s(x)` counting the
+// values of side `s` from the subtree's first value up to `x`: `total` is the count of each side,
+// `gaps` the sum of the gaps, `reach[s]` the sum of `gap × F_s` and `min` and `max` bound
+// `F_left - F_right`. A subtree placed after others only shifts `F`, so its parent can add it on
+// without pushing anything down.
+#[derive(Debug, Clone)]
+struct Node<T, A> {
+    value: T,
+    priority: u64,
+    children: [usize; 2],
+    gap: A,
+    total: [usize; 2],
+    gaps: A,
+    reach: [A; 2],
+    min: i64,
+    max: i64,
+}
+
+fn offset(counts: [usize; 2]) -> i64 {
+    counts[LEFT] as i64 - counts[RIGHT] as i64
+}
+
+/// Left and right lists with their similarity score and total sorted distance kept up to date on
+/// every edit.
+// Adding `v` to one side adds `v` times its count on the other side to the score, removing takes
+// it away again, so the score only needs the counts of each side.
+//
+// Pairing the sorted lists by rank, the distance is the area between their counting functions:
+// the sum of `gap × |F_left - F_right|` over the distinct values, with both counts capped at the
+// length of the shorter list. A treap over the distinct values keeps those sums, so an edit or an
+// unseen value costs O(log n). Values are kept once seen, their gaps still add up. Reading the
+// distance takes O(log n) for every run of values where the sign of `F_left - F_right` holds.
+#[derive(Debug, Clone)]
+pub struct SimilarityIndex<T, A> {
+    counts: [HashMap<T, usize>; 2],
+    nodes: Vec<Node<T, A>>,
+    root: usize,
+    seed: u64,
+    len: [usize; 2],
+    score: A,
+}
+
+impl<T: LocationId + Ord, A: Total<T>> Default for SimilarityIndex<T, A> {
+    fn default() -> Self {
+        SimilarityIndex::new()
+    }
+}
+
+impl<T: LocationId + Ord, A: Total<T>> SimilarityIndex<T, A> {
+    pub fn new() -> Self {
+        SimilarityIndex {
+            counts: [HashMap::new(), HashMap::new()],
+            nodes: Vec::new(),
+            root: NIL,
+            seed: 0x9E3779B97F4A7C15,
+            len: [0, 0],
+            score: A::from_count(0),
+        }
+    }
+
+    /// Index of two existing lists.
+    pub fn from_lists(left: &[T], right: &[T]) -> Self {
+        let mut index = SimilarityIndex::new();
+        for v in left {
+            index.insert_left(*v);
+        }
+        for v in right {
+            index.insert_right(*v);
+        }
+        index
+    }
+
+    fn count(&self, side: usize, value: &T) -> usize {
+        self.counts[side].get(value).copied().unwrap_or(0)
+    }
+
+    fn total(&self, n: usize) -> [usize; 2] {
+        if n == NIL { [0, 0] } else { self.nodes[n].total }
+    }
+
+    // Counts of both sides up to and including node `n`, after `off` values before its subtree
+    fn through(&self, n: usize, off: [usize; 2]) -> [usize; 2] {
+        let (left, value) = (self.total(self.nodes[n].children[0]), &self.nodes[n].value);
+        [LEFT, RIGHT].map(|s| off[s] + left[s] + self.count(s, value))
+    }
+
+    // Recompute the totals of `n` from its children
+    fn pull(&mut self, n: usize) {
+        let [l, r] = self.nodes[n].children;
+        let zero = A::from_count(0);
+
+        let (mut gaps, mut reach, mut min, mut max) = (zero, [zero; 2], i64::MAX, i64::MIN);
+        if l != NIL {
+            let c = &self.nodes[l];
+            (gaps, reach, min, max) = (c.gaps, c.reach, c.min, c.max);
+        }
+
+        let mut total = self.through(n, [0, 0]);
+        let gap = self.nodes[n].gap;
+        let here = offset(total);
+        gaps = gaps + gap;
+        reach = [LEFT, RIGHT].map(|s| reach[s] + gap * A::from_count(total[s]));
+        (min, max) = (min.min(here), max.max(here));
+
+        if r != NIL {
+            let c = &self.nodes[r];
+            gaps = gaps + c.gaps;
+            reach = [LEFT, RIGHT].map(|s| reach[s] + c.reach[s] + c.gaps * A::from_count(total[s]));
+            (min, max) = (min.min(here + c.min), max.max(here + c.max));
+            total = [LEFT, RIGHT].map(|s| total[s] + c.total[s]);
+        }
+
+        let node = &mut self.nodes[n];
+        (node.total, node.gaps, node.reach, node.min, node.max) = (total, gaps, reach, min, max);
+    }
+
+    // Split a subtree into the values below `value` and the rest
+    fn split(&mut self, n: usize, value: T) -> (usize, usize) {
+        if n == NIL {
+            return (NIL, NIL);
+        }
+
+        if self.nodes[n].value < value {
+            let (a, b) = self.split(self.nodes[n].children[1], value);
+            self.nodes[n].children[1] = a;
+            self.pull(n);
+            (n, b)
+        } else {
+            let (a, b) = self.split(self.nodes[n].children[0], value);
+            self.nodes[n].children[0] = b;
+            self.pull(n);
+            (a, n)
+        }
+    }
+
+    // Join two subtrees where every value of `a` is below those of `b`
+    fn merge(&mut self, a: usize, b: usize) -> usize {
+        if a == NIL || b == NIL {
+            return if a == NIL { b } else { a };
+        }
+
+        if self.nodes[a].priority > self.nodes[b].priority {
+            let right = self.merge(self.nodes[a].children[1], b);
+            self.nodes[a].children[1] = right;
+            self.pull(a);
+            a
+        } else {
+            let left = self.merge(a, self.nodes[b].children[0]);
+            self.nodes[b].children[0] = left;
+            self.pull(b);
+            b
+        }
+    }
+
+    // Recompute the totals on the path to `value`, first setting its gap when given one
+    fn refresh(&mut self, n: usize, value: &T, gap: Option<A>) {
+        if n == NIL {
+            return;
+        }
+
+        match value.cmp(&self.nodes[n].value) {
+            std::cmp::Ordering::Less => self.refresh(self.nodes[n].children[0], value, gap),
+            std::cmp::Ordering::Greater => self.refresh(self.nodes[n].children[1], value, gap),
+            std::cmp::Ordering::Equal => {
+                if let Some(gap) = gap {
+                    self.nodes[n].gap = gap;
+                }
+            }
+        }
+        self.pull(n);
+    }
+
+    // Add an unseen value to the tree, taking over part of its predecessor's gap
+    fn place(&mut self, value: T) {
+        if self.counts[LEFT].contains_key(&value) {
+            return;
+        }
+        self.counts[LEFT].insert(value, 0);
+        self.counts[RIGHT].insert(value, 0);
+
+        let (mut below, mut above) = (None, None);
+        let mut n = self.root;
+        while n != NIL {
+            let v = self.nodes[n].value;
+            (n, below, above) = if v < value { (self.nodes[n].children[1], Some(v), above) } else { (self.nodes[n].children[0], below, Some(v)) };
+        }
+
+        // xorshift, any spread of priorities keeps the expected depth logarithmic
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+
+        let zero = A::from_count(0);
+        let n = self.nodes.len();
+        self.nodes.push(Node {
+            value,
+            priority: self.seed,
+            children: [NIL, NIL],
+            gap: above.map_or(zero, |v| A::from_id(v) - A::from_id(value)),
+            total: [0, 0],
+            gaps: zero,
+            reach: [zero; 2],
+            min: 0,
+            max: 0,
+        });
+        self.pull(n);
+
+        let (a, b) = self.split(self.root, value);
+        let a = self.merge(a, n);
+        self.root = self.merge(a, b);
+        if let Some(v) = below {
+            self.refresh(self.root, &v, Some(A::from_id(value) - A::from_id(v)));
+        }
+    }
+
+    // The `k`th smallest value of one side, counting from 1
+    fn kth(&self, side: usize, mut k: usize) -> T {
+        let mut n = self.root;
+        loop {
+            let node = &self.nodes[n];
+            let left = self.total(node.children[0])[side];
+            let own = self.count(side, &node.value);
+            if k <= left {
+                n = node.children[0];
+            } else if k <= left + own {
+                return node.value;
+            } else {
+                k -= left + own;
+                n = node.children[1];
+            }
+        }
+    }
+
+    // Sum of `gap × |F_left - F_right|` over the values below `end`, or the whole subtree without
+    // one, after `off` values before the subtree
+    fn area(&self, n: usize, off: [usize; 2], end: Option<T>) -> A {
+        if n == NIL {
+            return A::from_count(0);
+        }
+
+        let node = &self.nodes[n];
+        let shift = offset(off);
+        if end.is_none() && (node.min + shift >= 0 || node.max + shift <= 0) {
+            let [left, right] = [LEFT, RIGHT].map(|s| node.reach[s] + node.gaps * A::from_count(off[s]));
+            return if node.min + shift >= 0 { left - right } else { right - left };
+        }
+
+        let [l, r] = node.children;
+        if end.is_some_and(|e| node.value >= e) {
+            return self.area(l, off, end);
+        }
+
+        let through = self.through(n, off);
+        let here = node.gap * A::from_count(offset(through).unsigned_abs() as usize);
+        self.area(l, off, None) + here + self.area(r, through, end)
+    }
+
+    // Sum of the gaps and of `gap × F_side` over the values from `start` on
+    fn suffix(&self, n: usize, off: [usize; 2], start: T, side: usize) -> (A, A) {
+        let zero = A::from_count(0);
+        if n == NIL {
+            return (zero, zero);
+        }
+
+        let node = &self.nodes[n];
+        let [l, r] = node.children;
+        let through = self.through(n, off);
+        if node.value < start {
+            return self.suffix(r, through, start, side);
+        }
+
+        let (gaps, reach) = self.suffix(l, off, start, side);
+        let (gaps, reach) = (gaps + node.gap, reach + node.gap * A::from_count(through[side]));
+        if r == NIL {
+            return (gaps, reach);
+        }
+        let right = &self.nodes[r];
+        (gaps + right.gaps, reach + right.reach[side] + right.gaps * A::from_count(through[side]))
+    }
+
+    // What one copy of `value` adds to the score given its count on the other side
+    fn contribution(value: T, other: usize) -> A {
+        A::from_id(value) * A::from_count(other)
+    }
+
+    fn insert(&mut self, side: usize, value: T) {
+        self.place(value);
+        self.score = self.score + Self::contribution(value, self.count(1 - side, &value));
+        *self.counts[side].get_mut(&value).unwrap() += 1;
+        self.refresh(self.root, &value, None);
+        self.len[side] += 1;
+    }
+
+    fn remove(&mut self, side: usize, value: &T) -> bool {
+        if self.count(side, value) == 0 {
+            return false;
+        }
+
+        *self.counts[side].get_mut(value).unwrap() -= 1;
+        self.refresh(self.root, value, None);
+        self.len[side] -= 1;
+        self.score = self.score - Self::contribution(*value, self.count(1 - side, value));
+        true
+    }
+
+    pub fn insert_left(&mut self, value: T) {
+        self.insert(LEFT, value);
+    }
+
+    pub fn insert_right(&mut self, value: T) {
+        self.insert(RIGHT, value);
+    }
+
+    /// Remove one copy of the value from the left list, `false` when it is not there.
+    pub fn remove_left(&mut self, value: &T) -> bool {
+        self.remove(LEFT, value)
+    }
+
+    /// Remove one copy of the value from the right list, `false` when it is not there.
+    pub fn remove_right(&mut self, value: &T) -> bool {
+        self.remove(RIGHT, value)
+    }
+
+    /// Similarity score of the lists, as [`crate::sum_similarity`] computes it.
+    pub fn similarity(&self) -> A {
+        self.score
+    }
+
+    /// Total distance between the sorted lists, as [`crate::sum_distances`] computes it. Extra
+    /// values in the longer list are left unpaired.
+    pub fn distance(&self) -> A {
+        let paired = self.len[LEFT].min(self.len[RIGHT]);
+        if paired == 0 {
+            return A::from_count(0);
+        }
+
+        // Past the last paired value of the longer list its count is capped at `paired`, so only
+        // the shorter list's count still moves and the gap to it is `paired - F_short`
+        let (long, short) = if self.len[LEFT] >= self.len[RIGHT] { (LEFT, RIGHT) } else { (RIGHT, LEFT) };
+        let last = self.kth(long, paired);
+
+        let before = self.area(self.root, [0, 0], Some(last));
+        let (gaps, reach) = self.suffix(self.root, [0, 0], last, short);
+        before + gaps * A::from_count(paired) - reach
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sum_distances, sum_similarity};
+    use std::time::{Duration, Instant};
+
+    fn depth<T, A>(index: &SimilarityIndex<T, A>, n: usize) -> usize {
+        if n == NIL {
+            return 0;
+        }
+        let [l, r] = index.nodes[n].children;
+        1 + depth(index, l).max(depth(index, r))
+    }
+
+    #[test]
+    fn given_sample_expect_both_totals() {
+        let index: SimilarityIndex<i32, i64> = SimilarityIndex::from_lists(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+
+        assert_eq!(index.similarity(), 31);
+        assert_eq!(index.distance(), 11);
+    }
+
+    #[test]
+    fn given_edits_expect_totals_match_recomputing() {
+        let mut index: SimilarityIndex<i32, i64> = SimilarityIndex::new();
+        let mut left: Vec<i32> = Vec::new();
+        let mut right: Vec<i32> = Vec::new();
+
+        // Small values so the lists share plenty of IDs
+        let mut seed: u32 = 7;
+        for step in 0..500 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let value = (seed >> 16) as i32 % 20;
+            let list = if step % 2 == 0 { &mut left } else { &mut right };
+
+            if seed.is_multiple_of(3) {
+                let removed = if step % 2 == 0 { index.remove_left(&value) } else { index.remove_right(&value) };
+                assert_eq!(removed, list.contains(&value));
+                if let Some(i) = list.iter().position(|v| *v == value) {
+                    list.remove(i);
+                }
+            } else {
+                if step % 2 == 0 { index.insert_left(value) } else { index.insert_right(value) }
+                list.push(value);
+            }
+
+            let mut sorted_left = left.clone();
+            let mut sorted_right = right.clone();
+            sorted_left.sort();
+            sorted_right.sort();
+            assert_eq!(index.similarity(), sum_similarity::<i32, i64, _>(&left, &right));
+            assert_eq!(index.distance(), sum_distances::<i32, i64, _>(&sorted_left, &sorted_right));
+        }
+    }
+
+    #[test]
+    fn given_uneven_edits_expect_matching_distance() {
+        let mut index: SimilarityIndex<i32, i64> = SimilarityIndex::new();
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+
+        let mut seed: u32 = 19;
+        for step in 0..400 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            let value = (seed >> 16) as i32 % 100 - 50;
+            // Mostly grow the left list so the lengths drift apart
+            if step % 5 == 0 {
+                index.insert_right(value);
+                right.push(value);
+            } else {
+                index.insert_left(value);
+                left.push(value);
+            }
+
+            left.sort();
+            right.sort();
+            assert_eq!(index.distance(), sum_distances::<i32, i64, _>(&left, &right));
+        }
+        assert!(index.nodes.len() <= 100);
+    }
+
+    #[test]
+    fn given_many_unseen_values_expect_shallow_tree_and_fast_inserts() {
+        let mut index: SimilarityIndex<i64, i64> = SimilarityIndex::new();
+        let n = 20_000;
+
+        // Values arriving in order would make an unbalanced tree a list
+        let start = Instant::now();
+        for v in 0..n {
+            index.insert_left(v);
+            index.insert_right(2 * n - v);
+        }
+
+        assert!(start.elapsed() < Duration::from_secs(10), "{:?}", start.elapsed());
+        assert!(depth(&index, index.root) <= 60, "depth {}", depth(&index, index.root));
+        assert_eq!(index.distance(), n * (n + 1));
+        assert_eq!(index.similarity(), 0);
+    }
+
+    #[test]
+    fn given_missing_value_expect_remove_refused() {
+        let mut index: SimilarityIndex<u64, u128> = SimilarityIndex::new();
+        index.insert_left(5);
+
+        assert!(!index.remove_right(&5));
+        assert!(index.remove_left(&5));
+        assert!(!index.remove_left(&5));
+        assert_eq!(index.similarity(), 0);
+        assert_eq!(index.distance(), 0);
+    }
+}
@@ -0,0 +1,514 @@
+//! Day 1: Historian Hysteria. Reconcile two lists of location IDs.
+
+pub mod external;
+pub mod index;
+pub mod matching;
+
+pub use index::SimilarityIndex;
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Sub};
+use std::str::FromStr;
+
+/// Integer type of the location IDs.
+pub trait LocationId: Copy + Eq + Hash + FromStr {}
+
+impl<T: Copy + Eq + Hash + FromStr> LocationId for T {}
+
+/// Integer type the distances and similarity of `T` IDs are summed in. It can be wider than `T`
+/// so totals of large lists do not overflow, like `i64` totals over `i32` IDs.
+pub trait Total<T>: Copy + Ord + Sum + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    fn from_id(id: T) -> Self;
+    fn from_count(count: usize) -> Self;
+}
+
+impl<T, A> Total<T> for A
+where
+    A: Copy + Ord + Sum + Add<Output = A> + Sub<Output = A> + Mul<Output = A> + From<T> + TryFrom<usize>,
+{
+    fn from_id(id: T) -> A {
+        A::from(id)
+    }
+
+    fn from_count(count: usize) -> A {
+        A::try_from(count).ok().expect("Count does not fit the total type")
+    }
+}
+
+/// Streaming parser yielding the IDs of each row. Columns are separated by tabs or runs of
+/// spaces and blank lines are skipped. Every row needs as many columns as the first one.
+pub struct Rows<'a, T = i32> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    skip_comments: bool,
+    columns: Option<usize>,
+    id: PhantomData<T>,
+}
+
+impl<'a, T: LocationId> Rows<'a, T> {
+    pub fn new(input: &'a str) -> Self {
+        Rows { lines: input.lines().enumerate(), skip_comments: false, columns: None, id: PhantomData }
+    }
+
+    /// Also skip lines starting with `#`.
+    pub fn skip_comments(mut self) -> Self {
+        self.skip_comments = true;
+        self
+    }
+
+    /// Require exactly this many columns on every row.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+}
+
+impl<T: LocationId> Iterator for Rows<'_, T> {
+    type Item = Result<Vec<T>, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (i, line) in self.lines.by_ref() {
+            let Some(line) = row_text(line, self.skip_comments) else {
+                continue;
+            };
+
+            let row = parse_row(line, *self.columns.get_or_insert(line.split_whitespace().count()));
+            return Some(row.map_err(|e| format!("Line {}: {}", i + 1, e)));
+        }
+
+        None
+    }
+}
+
+// The trimmed text of a line holding a row, `None` for blank lines and skipped comments
+pub(crate) fn row_text(line: &str, skip_comments: bool) -> Option<&str> {
+    let line = line.trim();
+    let skipped = line.is_empty() || (skip_comments && line.starts_with('#'));
+    (!skipped).then_some(line)
+}
+
+pub(crate) fn parse_row<T: LocationId>(line: &str, columns: usize) -> Result<Vec<T>, String> {
+    let values: Vec<&str> = line.split_whitespace().collect();
+    if values.len() != columns {
+        return Err(format!("expected {} columns, found {}", columns, values.len()));
+    }
+
+    values
+        .iter()
+        .map(|v| v.parse::<T>().map_err(|_| format!("invalid location ID '{}'", v)))
+        .collect()
+}
+
+/// Streaming parser yielding the `(left, right)` pair of each row, see [`Rows`].
+pub struct Pairs<'a, T = i32> {
+    rows: Rows<'a, T>,
+}
+
+impl<'a, T: LocationId> Pairs<'a, T> {
+    pub fn new(input: &'a str) -> Self {
+        Pairs { rows: Rows::new(input).columns(2) }
+    }
+
+    /// Also skip lines starting with `#`.
+    pub fn skip_comments(mut self) -> Self {
+        self.rows = self.rows.skip_comments();
+        self
+    }
+}
+
+impl<T: LocationId> Iterator for Pairs<'_, T> {
+    type Item = Result<(T, T), String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|row| row.map(|r| (r[0], r[1])))
+    }
+}
+
+/// Read the two columns of the puzzle input, failing on the first malformed row.
+pub fn read_lists<T: LocationId>(input: &str) -> Result<(Vec<T>, Vec<T>), String> {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for pair in Pairs::new(input) {
+        let (l, r) = pair?;
+        left.push(l);
+        right.push(r);
+    }
+
+    Ok((left, right))
+}
+
+/// Read any number of columns, failing on the first malformed row.
+pub fn read_columns<T: LocationId>(input: &str) -> Result<Vec<Vec<T>>, String> {
+    let mut columns: Vec<Vec<T>> = Vec::new();
+
+    for row in Rows::new(input) {
+        let row = row?;
+        columns.resize_with(row.len(), Vec::new);
+        for (column, id) in columns.iter_mut().zip(row) {
+            column.push(id);
+        }
+    }
+
+    Ok(columns)
+}
+
+/// Distance between two IDs.
+pub fn distance<T, A: Total<T>>(l: T, r: T) -> A {
+    let (l, r) = (A::from_id(l), A::from_id(r));
+    if l > r { l - r } else { r - l }
+}
+
+/// Sum the distances between the pairs of two lists. Both lists are expected to be sorted.
+pub fn sum_distances<'a, T, A, I>(left: I, right: I) -> A
+where
+    T: LocationId + 'a,
+    A: Total<T>,
+    I: IntoIterator<Item = &'a T>,
+{
+    left.into_iter().zip(right).map(|(l, r)| distance(*l, *r)).sum()
+}
+
+/// Number of times each value appears in the list.
+pub fn frequencies<'a, T, I>(list: I) -> HashMap<T, usize>
+where
+    T: LocationId + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    let mut frequencies: HashMap<T, usize> = HashMap::new();
+    for e in list.into_iter() {
+        *frequencies.entry(*e).or_insert(0) += 1;
+    }
+    frequencies
+}
+
+/// Sum each left value multiplied by the number of times it appears in the right list.
+pub fn sum_similarity<'a, T, A, I>(left: I, right: I) -> A
+where
+    T: LocationId + 'a,
+    A: Total<T>,
+    I: IntoIterator<Item = &'a T>,
+{
+    let frequencies = frequencies(right);
+
+    left
+        .into_iter()
+        .filter_map(|v| frequencies.get(v).map(|ct| A::from_id(*v) * A::from_count(*ct)))
+        .sum()
+}
+
+/// A pair of IDs matched by rank once both lists are sorted, with what it adds to each total.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pairing<T, A> {
+    pub rank: usize,
+    pub left: T,
+    pub right: T,
+    pub distance: A,
+    pub similarity: A,
+}
+
+/// Which contribution to order a pairing report by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportOrder {
+    Rank,
+    Distance,
+    Similarity,
+}
+
+/// Match the sorted lists by rank. The similarity of a pairing is its left ID multiplied by the
+/// times it appears in the right list, so both columns sum to the part 1 and part 2 totals.
+pub fn pairings<T, A>(left: &[T], right: &[T]) -> Vec<Pairing<T, A>>
+where
+    T: LocationId + Ord,
+    A: Total<T>,
+{
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+    left.sort();
+    right.sort();
+    let frequencies = frequencies(&right);
+
+    left.iter()
+        .zip(right.iter())
+        .enumerate()
+        .map(|(rank, (l, r))| Pairing {
+            rank: rank + 1,
+            left: *l,
+            right: *r,
+            distance: distance(*l, *r),
+            similarity: frequencies.get(l).map_or(A::from_count(0), |ct| A::from_id(*l) * A::from_count(*ct)),
+        })
+        .collect()
+}
+
+/// Order the pairings by the largest contribution first. Ties keep their rank order.
+pub fn sort_pairings<T, A: Total<T>>(pairings: &mut [Pairing<T, A>], order: ReportOrder) {
+    match order {
+        ReportOrder::Rank => pairings.sort_by_key(|p| p.rank),
+        ReportOrder::Distance => pairings.sort_by(|a, b| b.distance.cmp(&a.distance).then(a.rank.cmp(&b.rank))),
+        ReportOrder::Similarity => {
+            pairings.sort_by(|a, b| b.similarity.cmp(&a.similarity).then(a.rank.cmp(&b.rank)))
+        }
+    }
+}
+
+/// The pairings as an aligned table with a header.
+pub fn report_table<T: Display, A: Display>(pairings: &[Pairing<T, A>]) -> String {
+    let mut lines = vec![format!("{:>6}  {:>12}  {:>12}  {:>12}  {:>14}", "rank", "left", "right", "distance", "similarity")];
+    for p in pairings {
+        lines.push(format!(
+            "{:>6}  {:>12}  {:>12}  {:>12}  {:>14}",
+            p.rank, p.left, p.right, p.distance, p.similarity
+        ));
+    }
+    lines.join("\n")
+}
+
+/// The pairings as CSV with a header row.
+pub fn report_csv<T: Display, A: Display>(pairings: &[Pairing<T, A>]) -> String {
+    let mut lines = vec![String::from("rank,left,right,distance,similarity")];
+    for p in pairings {
+        lines.push(format!("{},{},{},{},{}", p.rank, p.left, p.right, p.distance, p.similarity));
+    }
+    lines.join("\n")
+}
+
+/// Distances and similarity between every pair of columns.
+#[derive(Debug, PartialEq)]
+pub struct Reconciliation<A> {
+    /// Total distance between the sorted columns `i` and `j`, the same both ways.
+    pub distances: Vec<Vec<A>>,
+    /// Similarity of column `i` scored against the frequencies of column `j`.
+    pub similarity: Vec<Vec<A>>,
+}
+
+impl<A: Copy + Ord> Reconciliation<A> {
+    /// The two distinct columns with the smallest distance between them.
+    pub fn best_agreement(&self) -> Option<(usize, usize, A)> {
+        let n = self.distances.len();
+        (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .map(|(i, j)| (i, j, self.distances[i][j]))
+            .min_by_key(|(i, j, d)| (*d, *i, *j))
+    }
+
+    /// Both matrices and the best agreeing columns, numbered from 1.
+    pub fn summary(&self) -> String
+    where
+        A: Display,
+    {
+        let matrix = |title: &str, values: &Vec<Vec<A>>| {
+            let mut lines = vec![title.to_string()];
+            for row in values {
+                lines.push(row.iter().map(|v| format!("{:>12}", v)).collect::<Vec<String>>().join(""));
+            }
+            lines.join("\n")
+        };
+
+        let best = match self.best_agreement() {
+            Some((i, j, d)) => format!("Columns {} and {} agree best with distance {}", i + 1, j + 1, d),
+            None => String::from("Need at least two columns to compare"),
+        };
+
+        [matrix("Distances", &self.distances), matrix("Similarity", &self.similarity), best].join("\n\n")
+    }
+}
+
+/// Compare every pair of columns, the same way part 1 and part 2 compare the left and right lists.
+pub fn reconcile<T, A>(columns: &[Vec<T>]) -> Reconciliation<A>
+where
+    T: LocationId + Ord,
+    A: Total<T>,
+{
+    let sorted: Vec<Vec<T>> = columns
+        .iter()
+        .map(|c| {
+            let mut c = c.clone();
+            c.sort();
+            c
+        })
+        .collect();
+
+    let distances = sorted
+        .iter()
+        .map(|a| sorted.iter().map(|b| sum_distances(a, b)).collect())
+        .collect();
+    let similarity = columns
+        .iter()
+        .map(|a| columns.iter().map(|b| sum_similarity(a, b)).collect())
+        .collect();
+
+    Reconciliation { distances, similarity }
+}
+
+/// Total distance between the sorted lists.
+pub fn part_1(input: &str) -> i64 {
+    let (mut left, mut right) = read_lists::<i32>(input).expect("Invalid location lists");
+    left.sort();
+    right.sort();
+
+    sum_distances(&left, &right)
+}
+
+/// Similarity score of the lists.
+pub fn part_2(input: &str) -> i64 {
+    let (left, right) = read_lists::<i32>(input).expect("Invalid location lists");
+    sum_similarity(&left, &right)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given_sample_score_part_1() {
+        let mut left_1 = Vec::from([3, 4, 2, 1, 3, 3]);
+        let mut right_1 = Vec::from([4, 3, 5, 3, 9, 3]);
+
+        left_1.sort();
+        right_1.sort();
+
+        let score: i32 = sum_distances(&left_1, &right_1);
+        assert_eq!(score, 11)
+    }
+
+    #[test]
+    fn given_sample_score_part_2() {
+        let left_1 = Vec::from([3, 4, 2, 1, 3, 3]);
+        let right_1 = Vec::from([4, 3, 5, 3, 9, 3]);
+
+
+        let score: i32 = sum_similarity(&left_1, &right_1);
+        assert_eq!(score, 31)
+    }
+
+    #[test]
+    fn given_sample_input_expect_both_parts() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+        assert_eq!(part_1(input), 11);
+        assert_eq!(part_2(input), 31);
+    }
+
+    #[test]
+    fn given_tabs_blank_lines_and_comments_expect_pairs() {
+        let input = "# left right\n3\t4\n\n4 \t 3\n  2     5  \n";
+        let pairs: Result<Vec<(i32, i32)>, String> = Pairs::new(input).skip_comments().collect();
+
+        assert_eq!(pairs.unwrap(), vec![(3, 4), (4, 3), (2, 5)]);
+        assert_eq!(Pairs::<i32>::new(input).next().unwrap().unwrap_err(), "Line 1: expected 2 columns, found 3");
+    }
+
+    #[test]
+    fn given_malformed_rows_expect_line_numbered_errors() {
+        assert_eq!(read_lists::<i32>("1   2\n3   4   5\n").unwrap_err(), "Line 2: expected 2 columns, found 3");
+        assert_eq!(read_lists::<i32>("1   2\n\n7\n").unwrap_err(), "Line 3: expected 2 columns, found 1");
+        assert_eq!(
+            read_lists::<i32>("1   2147483648\n").unwrap_err(),
+            "Line 1: invalid location ID '2147483648'"
+        );
+        assert_eq!(read_lists::<i32>("x   2\n").unwrap_err(), "Line 1: invalid location ID 'x'");
+    }
+
+    #[test]
+    fn given_parser_expect_it_streams_past_errors() {
+        let mut pairs = Pairs::<i32>::new("1   2\nbad\n3   4");
+
+        assert_eq!(pairs.next(), Some(Ok((1, 2))));
+        assert!(pairs.next().unwrap().is_err());
+        assert_eq!(pairs.next(), Some(Ok((3, 4))));
+        assert_eq!(pairs.next(), None);
+    }
+
+    #[test]
+    fn given_ids_near_i32_limits_expect_wider_totals() {
+        let left = [i32::MIN, 0, i32::MAX];
+        let right = [i32::MAX, i32::MAX, i32::MAX];
+
+        let distances: i64 = sum_distances(&left, &right);
+        assert_eq!(distances, 2 * i32::MAX as i64 + 1 + i32::MAX as i64);
+
+        let similarity: i64 = sum_similarity(&left, &right);
+        assert_eq!(similarity, 3 * i32::MAX as i64);
+    }
+
+    #[test]
+    fn given_u64_ids_expect_u128_totals() {
+        let (mut left, mut right) = read_lists::<u64>("18446744073709551615   0\n0   18446744073709551615\n").unwrap();
+        left.sort();
+        right.sort();
+
+        let distances: u128 = sum_distances(&left, &right);
+        assert_eq!(distances, 0);
+
+        let similarity: u128 = sum_similarity(&[u64::MAX, u64::MAX], &[u64::MAX, u64::MAX]);
+        assert_eq!(similarity, 4 * u64::MAX as u128);
+
+        let distances: u128 = sum_distances(&[0, 0], &[u64::MAX, u64::MAX]);
+        assert_eq!(distances, 2 * u64::MAX as u128);
+    }
+
+    #[test]
+    fn given_sample_expect_pairings_sum_to_totals() {
+        let left = [3, 4, 2, 1, 3, 3];
+        let right = [4, 3, 5, 3, 9, 3];
+        let pairings: Vec<Pairing<i32, i64>> = pairings(&left, &right);
+
+        assert_eq!(pairings.len(), 6);
+        assert_eq!(
+            pairings[5],
+            Pairing { rank: 6, left: 4, right: 9, distance: 5, similarity: 4 }
+        );
+        assert_eq!(pairings.iter().map(|p| p.distance).sum::<i64>(), 11);
+        assert_eq!(pairings.iter().map(|p| p.similarity).sum::<i64>(), 31);
+    }
+
+    #[test]
+    fn given_sample_expect_csv_sorted_by_contribution() {
+        let mut pairings: Vec<Pairing<i32, i64>> = pairings(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+
+        sort_pairings(&mut pairings, ReportOrder::Distance);
+        assert_eq!(
+            report_csv(&pairings),
+            "\
+rank,left,right,distance,similarity
+6,4,9,5,4
+1,1,3,2,0
+5,3,5,2,9
+2,2,3,1,0
+4,3,4,1,9
+3,3,3,0,9"
+        );
+
+        sort_pairings(&mut pairings, ReportOrder::Similarity);
+        assert_eq!(pairings.iter().map(|p| p.rank).collect::<Vec<usize>>(), vec![3, 4, 5, 6, 1, 2]);
+
+        sort_pairings(&mut pairings, ReportOrder::Rank);
+        assert_eq!(report_table(&pairings).lines().count(), 7);
+    }
+
+    #[test]
+    fn given_three_columns_expect_pairwise_matrices() {
+        let columns = read_columns::<i32>("3 4 3\n4 3 4\n2 5 2\n1 3 1\n3 9 3\n3 3 4\n").unwrap();
+        let reconciliation: Reconciliation<i64> = reconcile(&columns);
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(reconciliation.distances, vec![vec![0, 11, 1], vec![11, 0, 10], vec![1, 10, 0]]);
+        assert_eq!(reconciliation.similarity[0][1], 31);
+        assert_eq!(reconciliation.similarity[2][0], 29);
+        assert_eq!(reconciliation.best_agreement(), Some((0, 2, 1)));
+        assert!(reconciliation.summary().ends_with("Columns 1 and 3 agree best with distance 1"));
+    }
+
+    #[test]
+    fn given_ragged_columns_expect_line_numbered_error() {
+        assert_eq!(
+            read_columns::<i32>("1 2 3\n4 5\n").unwrap_err(),
+            "Line 2: expected 3 columns, found 2"
+        );
+        assert_eq!(read_columns::<i32>("").unwrap(), Vec::<Vec<i32>>::new());
+    }
+}
@@ -1,93 +1,110 @@
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+mod temp_runs;
 
-
-fn sum_distances<'a, I>(left: I, right: I) -> i32 
-where 
-    I: IntoIterator<Item = &'a i32>,
-{
-    left
-        .into_iter()
-        .zip(right.into_iter())
-        .map(|t| (t.1 - t.0).abs())
-        .sum()
-}
-
-fn sum_similarity<'a, I>(left: I, right: I) -> i32 
-where 
-    I: IntoIterator<Item = &'a i32>,
-{
-    let mut frequencies: HashMap<i32, i32> = HashMap::new();
-    for e in right.into_iter() {
-        *frequencies.entry(*e).or_insert(0) += 1;
-    }
-
-    return left
-        .into_iter()
-        .map(|v| {
-            match frequencies.get(v) {
-                None => 0, 
-                Some(ct) => v * *ct
-            }
-        })
-        .sum();
-}
+use day1::external::ExternalSort;
+use day1::matching::{self, Matching, Metric};
+use day1::{Pairing, ReportOrder};
 
 fn main() {
-    let file = File::open("input.txt").expect("Unable to open file");
-    let reader = BufReader::new(file);
-
-    let mut left = Vec::new();
-    let mut right = Vec::new();
-
-
-    for line in reader.lines() {
-        let l = line.expect("Unable to read file");
-
-        let mut res = l.split_whitespace();
-        let l: i32 = res.next().unwrap().parse().unwrap();
-        let r: i32 = res.next().unwrap().parse().unwrap();
-
-        left.push(l);
-        right.push(r);
-
+    // day1 --external [RUN_SIZE] sorts through temporary files instead of in memory
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(i) = args.iter().position(|a| a == "--external") {
+        let run_size = match args.get(i + 1).filter(|r| !r.starts_with("--")).map(|r| r.parse::<usize>()) {
+            None => day1::external::DEFAULT_RUN_SIZE,
+            Some(Ok(size)) => size,
+            Some(Err(_)) => {
+                eprintln!("Invalid run size '{}'", args[i + 1]);
+                std::process::exit(1);
+            }
+        };
+
+        let file = std::fs::File::open("input.txt").expect("Unable to open file");
+        let reader = std::io::BufReader::new(file);
+        let totals = temp_runs::TempRuns::new(&std::env::temp_dir())
+            .and_then(|runs| ExternalSort::new(runs).run_size(run_size).totals::<i32, i64, _>(reader));
+        match totals {
+            Ok((distance, similarity)) => {
+                println!("Part 1: {}", distance);
+                println!("Part 2: {}", similarity);
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
     }
 
-    left.sort();
-    right.sort();
-
-    let part_one = sum_distances(&left, &right);
-    let part_two = sum_similarity(&left, &right);
-
-    println!("Part 1: {}", part_one);
-    println!("Part 2: {}", part_two);
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
+    let input = std::fs::read_to_string("input.txt").expect("Unable to open file");
 
-    #[test]
-    fn given_sample_score_part_1() {
-        let mut left_1 = Vec::from([3, 4, 2, 1, 3, 3]);
-        let mut right_1 = Vec::from([4, 3, 5, 3, 9, 3]);
-
-        left_1.sort();
-        right_1.sort();
+    // day1 --columns compares every column of a file with any number of them
+    if args.iter().any(|a| a == "--columns") {
+        match day1::read_columns::<i32>(&input) {
+            Ok(columns) => println!("{}", day1::reconcile::<i32, i64>(&columns).summary()),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
 
-        let score = sum_distances(&left_1, &right_1);
-        assert_eq!(score, 11)
+    let (left, right) = match day1::read_lists::<i32>(&input) {
+        Ok(lists) => lists,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    // day1 --report [table|csv] [--sort rank|distance|similarity]
+    if let Some(i) = args.iter().position(|a| a == "--report") {
+        let format = args.get(i + 1).filter(|f| !f.starts_with("--")).map_or("table", |f| f.as_str());
+        let order = match args.iter().position(|a| a == "--sort").and_then(|i| args.get(i + 1)).map(|s| s.as_str()) {
+            None | Some("distance") => ReportOrder::Distance,
+            Some("similarity") => ReportOrder::Similarity,
+            Some("rank") => ReportOrder::Rank,
+            Some(other) => {
+                eprintln!("Unknown sort '{}', expected rank, distance or similarity", other);
+                std::process::exit(1);
+            }
+        };
+
+        let mut pairings: Vec<Pairing<i32, i64>> = day1::pairings(&left, &right);
+        day1::sort_pairings(&mut pairings, order);
+        match format {
+            "csv" => println!("{}", day1::report_csv(&pairings)),
+            "table" => println!("{}", day1::report_table(&pairings)),
+            other => {
+                eprintln!("Unknown report format '{}', expected table or csv", other);
+                std::process::exit(1);
+            }
+        }
+        return;
     }
-    
-    #[test]
-    fn given_sample_score_part_2() {
-        let mut left_1 = Vec::from([3, 4, 2, 1, 3, 3]);
-        let mut right_1 = Vec::from([4, 3, 5, 3, 9, 3]);
 
-    
-        let score = sum_similarity(&left_1, &right_1);
-        assert_eq!(score, 31)
+    // day1 --match [rank|hungarian|nearest] [--metric absolute|squared|relative]
+    if let Some(i) = args.iter().position(|a| a == "--match") {
+        let metric = match args.iter().position(|a| a == "--metric").and_then(|i| args.get(i + 1)) {
+            None => Metric::Absolute,
+            Some(name) => Metric::from_name(name).unwrap_or_else(|| {
+                eprintln!("Unknown metric '{}', expected absolute, squared or relative", name);
+                std::process::exit(1);
+            }),
+        };
+
+        match args.get(i + 1).filter(|m| !m.starts_with("--")) {
+            None => println!("{}", matching::compare(&left, &right, metric)),
+            Some(name) => match Matching::from_name(name) {
+                Some(m) => println!("{}", matching::assign(&left, &right, m, metric).cost),
+                None => {
+                    eprintln!("Unknown matching '{}', expected rank, hungarian or nearest", name);
+                    std::process::exit(1);
+                }
+            },
+        }
+        return;
     }
 
-}
\ No newline at end of file
+    println!("Part 1: {}", day1::part_1(&input));
+    println!("Part 2: {}", day1::part_2(&input));
+}
@@ -0,0 +1,304 @@
+//! Other ways to match the left and right lists, and other costs for a matched pair.
+
+use std::collections::BTreeMap;
+
+use crate::LocationId;
+
+/// Cost of matching two IDs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Metric {
+    /// `|a - b|`, the puzzle's distance.
+    Absolute,
+    /// `(a - b)²`, punishing large gaps more.
+    Squared,
+    /// `|a - b|` as a fraction of the larger magnitude, so large IDs may differ more.
+    Relative,
+}
+
+impl Metric {
+    pub const ALL: [Metric; 3] = [Metric::Absolute, Metric::Squared, Metric::Relative];
+
+    pub fn from_name(name: &str) -> Option<Metric> {
+        Metric::ALL.into_iter().find(|m| m.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Metric::Absolute => "absolute",
+            Metric::Squared => "squared",
+            Metric::Relative => "relative",
+        }
+    }
+
+    pub fn cost(&self, a: f64, b: f64) -> f64 {
+        let gap = (a - b).abs();
+        match self {
+            Metric::Absolute => gap,
+            Metric::Squared => gap * gap,
+            Metric::Relative if gap == 0.0 => 0.0,
+            Metric::Relative => gap / a.abs().max(b.abs()),
+        }
+    }
+}
+
+/// How the left IDs are matched with the right ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matching {
+    /// Smallest with smallest once both lists are sorted, as part 1 does.
+    Rank,
+    /// The assignment with the lowest total cost, found with the Hungarian algorithm.
+    Hungarian,
+    /// Each left ID in list order takes the cheapest of its nearest remaining right IDs.
+    Nearest,
+}
+
+impl Matching {
+    pub const ALL: [Matching; 3] = [Matching::Rank, Matching::Hungarian, Matching::Nearest];
+
+    pub fn from_name(name: &str) -> Option<Matching> {
+        Matching::ALL.into_iter().find(|m| m.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Matching::Rank => "rank",
+            Matching::Hungarian => "hungarian",
+            Matching::Nearest => "nearest",
+        }
+    }
+}
+
+/// Matched pairs of IDs and their total cost. IDs left over in the longer list are unmatched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment<T> {
+    pub pairs: Vec<(T, T)>,
+    pub cost: f64,
+}
+
+/// Column of each row in the assignment with the lowest total cost. Needs no more rows than columns.
+// Shortest augmenting paths with row and column potentials, O(rows² × columns)
+pub fn hungarian(costs: &[Vec<f64>]) -> Vec<usize> {
+    let n = costs.len();
+    let m = costs.first().map_or(0, |r| r.len());
+    assert!(n <= m, "More rows than columns");
+
+    // 1-based, column 0 holds the row being added
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    let mut row_of = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut j0 = 0;
+        let mut min_v = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+
+        loop {
+            used[j0] = true;
+            let i0 = row_of[j0];
+            let mut delta = f64::INFINITY;
+            let mut j1 = 0;
+
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let reduced = costs[i0 - 1][j - 1] - u[i0] - v[j];
+                if reduced < min_v[j] {
+                    min_v[j] = reduced;
+                    way[j] = j0;
+                }
+                if min_v[j] < delta {
+                    delta = min_v[j];
+                    j1 = j;
+                }
+            }
+
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min_v[j] -= delta;
+                }
+            }
+
+            j0 = j1;
+            if row_of[j0] == 0 {
+                break;
+            }
+        }
+
+        // Flip the augmenting path back to the start
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut columns = vec![0; n];
+    for j in 1..=m {
+        if row_of[j] != 0 {
+            columns[row_of[j] - 1] = j - 1;
+        }
+    }
+    columns
+}
+
+fn rank<T: LocationId + Ord>(left: &[T], right: &[T]) -> Vec<(T, T)> {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+    left.sort();
+    right.sort();
+    left.into_iter().zip(right).collect()
+}
+
+fn optimal<T: LocationId + Into<f64>>(left: &[T], right: &[T], metric: Metric) -> Vec<(T, T)> {
+    // The algorithm wants the shorter list as the rows
+    let flipped = left.len() > right.len();
+    let (rows, columns) = if flipped { (right, left) } else { (left, right) };
+
+    let costs: Vec<Vec<f64>> = rows
+        .iter()
+        .map(|r| columns.iter().map(|c| metric.cost((*r).into(), (*c).into())).collect())
+        .collect();
+
+    hungarian(&costs)
+        .into_iter()
+        .enumerate()
+        .map(|(i, j)| if flipped { (columns[j], rows[i]) } else { (rows[i], columns[j]) })
+        .collect()
+}
+
+fn nearest<T: LocationId + Ord + Into<f64>>(left: &[T], right: &[T], metric: Metric) -> Vec<(T, T)> {
+    let mut remaining: BTreeMap<T, usize> = BTreeMap::new();
+    for r in right {
+        *remaining.entry(*r).or_insert(0) += 1;
+    }
+
+    let mut pairs = Vec::new();
+    for l in left {
+        let below = remaining.range(..=*l).next_back().map(|(v, _)| *v);
+        let above = remaining.range(*l..).next().map(|(v, _)| *v);
+        let cost = |v: T| metric.cost((*l).into(), v.into());
+
+        let chosen = match (below, above) {
+            (Some(b), Some(a)) => if cost(b) <= cost(a) { b } else { a },
+            (Some(v), None) | (None, Some(v)) => v,
+            (None, None) => break,
+        };
+
+        let count = remaining.get_mut(&chosen).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            remaining.remove(&chosen);
+        }
+        pairs.push((*l, chosen));
+    }
+    pairs
+}
+
+/// Match the lists with a strategy and total the metric over the matched pairs.
+pub fn assign<T>(left: &[T], right: &[T], matching: Matching, metric: Metric) -> Assignment<T>
+where
+    T: LocationId + Ord + Into<f64>,
+{
+    let pairs = match matching {
+        Matching::Rank => rank(left, right),
+        Matching::Hungarian => optimal(left, right, metric),
+        Matching::Nearest => nearest(left, right, metric),
+    };
+    let cost = pairs.iter().map(|(l, r)| metric.cost((*l).into(), (*r).into())).sum();
+
+    Assignment { pairs, cost }
+}
+
+/// The total cost of every strategy under one metric, a line each.
+pub fn compare<T>(left: &[T], right: &[T], metric: Metric) -> String
+where
+    T: LocationId + Ord + Into<f64>,
+{
+    Matching::ALL
+        .iter()
+        .map(|m| format!("{:<10} {}", m.name(), assign(left, right, *m, metric).cost))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: [i32; 6] = [3, 4, 2, 1, 3, 3];
+    const RIGHT: [i32; 6] = [4, 3, 5, 3, 9, 3];
+
+    #[test]
+    fn given_sample_expect_rank_matches_part_1() {
+        let assignment = assign(&LEFT, &RIGHT, Matching::Rank, Metric::Absolute);
+
+        assert_eq!(assignment.pairs, vec![(1, 3), (2, 3), (3, 3), (3, 4), (3, 5), (4, 9)]);
+        assert_eq!(assignment.cost, 11.0);
+    }
+
+    #[test]
+    fn given_cost_matrix_expect_cheapest_assignment() {
+        let costs = vec![vec![4.0, 1.0, 3.0], vec![2.0, 0.0, 5.0], vec![3.0, 2.0, 2.0]];
+
+        assert_eq!(hungarian(&costs), vec![1, 0, 2]);
+        assert_eq!(hungarian(&[vec![7.0, 3.0, 9.0]]), vec![1]);
+    }
+
+    #[test]
+    fn given_greedy_trap_expect_nearest_costs_more() {
+        let (left, right) = ([5, 1], [4, 8]);
+
+        assert_eq!(assign(&left, &right, Matching::Nearest, Metric::Absolute).pairs, vec![(5, 4), (1, 8)]);
+        assert_eq!(assign(&left, &right, Matching::Nearest, Metric::Absolute).cost, 8.0);
+        assert_eq!(assign(&left, &right, Matching::Hungarian, Metric::Absolute).cost, 6.0);
+        assert_eq!(assign(&left, &right, Matching::Rank, Metric::Absolute).cost, 6.0);
+    }
+
+    #[test]
+    fn given_any_metric_expect_hungarian_never_beaten() {
+        let mut seed: u32 = 11;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) as i32 % 500 + 1
+        };
+        let left: Vec<i32> = (0..40).map(|_| next()).collect();
+        let right: Vec<i32> = (0..40).map(|_| next()).collect();
+
+        for metric in Metric::ALL {
+            let best = assign(&left, &right, Matching::Hungarian, metric).cost;
+            let rank = assign(&left, &right, Matching::Rank, metric).cost;
+            let nearest = assign(&left, &right, Matching::Nearest, metric).cost;
+
+            assert!(best <= rank + 1e-9 && best <= nearest + 1e-9, "{}", metric.name());
+            // Sorting is already optimal when the cost only grows with the gap
+            if metric != Metric::Relative {
+                assert_eq!(best, rank, "{}", metric.name());
+            }
+        }
+    }
+
+    #[test]
+    fn given_lists_of_different_lengths_expect_shorter_fully_matched() {
+        let assignment = assign(&[1, 2, 3], &[2], Matching::Hungarian, Metric::Squared);
+
+        assert_eq!(assignment.pairs, vec![(2, 2)]);
+        assert_eq!(assign(&[1, 2, 3], &[2], Matching::Nearest, Metric::Absolute).pairs, vec![(1, 2)]);
+        assert_eq!(assign(&[9], &[1, 2, 8], Matching::Hungarian, Metric::Absolute).pairs, vec![(9, 8)]);
+    }
+
+    #[test]
+    fn given_metrics_expect_costs() {
+        assert_eq!(Metric::Absolute.cost(3.0, 7.0), 4.0);
+        assert_eq!(Metric::Squared.cost(3.0, 7.0), 16.0);
+        assert_eq!(Metric::Relative.cost(100.0, 80.0), 0.2);
+        assert_eq!(Metric::Relative.cost(0.0, 0.0), 0.0);
+        assert_eq!(Metric::from_name("squared"), Some(Metric::Squared));
+        assert_eq!(Matching::from_name("greedy"), None);
+    }
+}
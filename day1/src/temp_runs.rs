@@ -0,0 +1,82 @@
+//! Sorted runs of the external sort kept in temporary files.
+
+use std::fs::{DirBuilder, File, OpenOptions};
+use std::io::{BufReader, BufWriter, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use day1::external::RunStore;
+
+/// Runs as files in a private directory, removed once merged or when the store is dropped.
+// Other users can write to the shared temporary directory, so every run lives in a directory only
+// this sort created and files are only ever created new, never opened through an existing name.
+pub struct TempRuns {
+    dir: PathBuf,
+    paths: Vec<Option<PathBuf>>,
+}
+
+impl TempRuns {
+    /// Create a new directory for the runs inside `parent`.
+    pub fn new(parent: &Path) -> Result<Self, String> {
+        let mut builder = DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+
+        for attempt in 0.. {
+            let dir = parent.join(format!("day1-{}-{}", std::process::id(), attempt));
+            match builder.create(&dir) {
+                Ok(()) => return Ok(TempRuns { dir, paths: Vec::new() }),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(format!("Unable to create {}: {}", dir.display(), e)),
+            }
+        }
+        unreachable!()
+    }
+
+    fn path(&self, run: usize) -> Result<&PathBuf, String> {
+        self.paths[run].as_ref().ok_or_else(|| format!("Run {} was removed", run))
+    }
+}
+
+impl RunStore for TempRuns {
+    type Writer = BufWriter<File>;
+    type Reader = BufReader<File>;
+
+    fn create(&mut self) -> Result<(usize, BufWriter<File>), String> {
+        let run = self.paths.len();
+        let path = self.dir.join(format!("{}.run", run));
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| format!("Unable to create {}: {}", path.display(), e))?;
+
+        self.paths.push(Some(path));
+        Ok((run, BufWriter::new(file)))
+    }
+
+    fn finish(&mut self, run: usize, mut writer: BufWriter<File>) -> Result<(), String> {
+        let path = self.path(run)?;
+        writer.flush().map_err(|e| format!("Unable to write {}: {}", path.display(), e))
+    }
+
+    fn open(&self, run: usize) -> Result<BufReader<File>, String> {
+        let path = self.path(run)?;
+        let file = File::open(path).map_err(|e| format!("Unable to open {}: {}", path.display(), e))?;
+        Ok(BufReader::new(file))
+    }
+
+    fn remove(&mut self, run: usize) {
+        if let Some(path) = self.paths[run].take() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+impl Drop for TempRuns {
+    fn drop(&mut self) {
+        for run in 0..self.paths.len() {
+            self.remove(run);
+        }
+        let _ = std::fs::remove_dir(&self.dir);
+    }
+}
@@ -0,0 +1,171 @@
+//! Day 10: Hoof It. Score the hiking trails of a topographic map.
+
+/// Heights stored row by row, `-1` for impassable tiles.
+#[derive(Debug)]
+pub struct TopoMap {
+    pub rows: usize,
+    pub columns: usize,
+    pub map: Vec<i8>,
+}
+
+/// A trailhead and every height 9 position reached from it, once per distinct trail.
+#[derive(Debug)]
+pub struct Trail {
+    pub start: (usize, usize),
+    pub points: Vec<(usize, usize)>,
+}
+
+impl TopoMap {
+    /// Read the map from lines of digits.
+    pub fn new(input: &str) -> TopoMap {
+        let mut iter = input.lines().peekable();
+        let columns: usize = iter.peek().unwrap().len();
+        let rows: usize = iter.count();
+        let map: Vec<i8> = input
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| {
+                match c.to_digit(10) {
+                    Some(d) => d as i8,
+                    None => -1,
+                }
+            })
+            .collect();
+        TopoMap { columns, rows, map }
+    }
+
+    /// Height at the row and column, `None` when outside the map.
+    pub fn get(&self, row: usize, col: usize) -> Option<i8> {
+        if !(0..self.rows).contains(&row) || !(0..self.columns).contains(&col) {
+            return None;
+        }
+
+        Some(self.map[row * self.columns + col])
+    }
+
+    /// Orthogonal neighbours of a position that do not underflow. They may be past the far edges.
+    pub fn get_adjacent(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let mut result = Vec::with_capacity(8);
+        let steps = [
+            (-1, 0),
+            (0, 1),
+            (1, 0),
+            (0, -1),
+        ];
+
+        for (dr, dc) in steps {
+            let r = row.checked_add_signed(dr);
+            let c = col.checked_add_signed(dc);
+
+            if let (Some(r), Some(c)) = (r, c) {
+                result.push((r, c));
+            }
+        }
+
+        result
+    }
+}
+
+/// Follow every uphill trail from a position.
+pub fn read_trails_at(map: &TopoMap, row: usize, col: usize) -> Trail {
+    let mut queue: Vec<(usize, usize)> = vec![(row, col)];
+    let mut trails = Vec::new();
+
+    while let Some((row, col)) = queue.pop() {
+        let current = map.get(row, col).unwrap();
+
+        if current == 9 {
+            trails.push((row, col));
+            continue;
+        }
+
+        for (r, c) in map.get_adjacent(row, col).iter() {
+            if let Some(val) = map.get(*r, *c) {
+                if val == current + 1 {
+                    queue.push((*r, *c));
+                }
+            }
+        }
+    }
+
+    Trail { start: (row, col), points: trails }
+}
+
+/// Follow the trails of every trailhead.
+pub fn score_map(map: &TopoMap) -> Vec<Trail> {
+    let mut trails = Vec::new();
+    for r in 0..map.rows {
+        for c in 0..map.columns {
+            if let Some(val) = map.get(r, c) {
+                if val == 0 {
+                    trails.push(read_trails_at(map, r, c));
+                }
+            }
+        }
+    }
+    trails
+}
+
+/// Sum of the number of summits reachable from each trailhead.
+pub fn part_1(input: &str) -> usize {
+    let map = TopoMap::new(input);
+    score_map(&map)
+        .iter_mut()
+        .map(|e| {
+            e.points.sort();
+            e.points.dedup();
+            e.points.len()
+        })
+        .sum()
+}
+
+/// Sum of the number of distinct trails from each trailhead.
+pub fn part_2(input: &str) -> usize {
+    let map = TopoMap::new(input);
+    score_map(&map)
+        .iter()
+        .map(|e| e.points.len())
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    const INPUT: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn test_sample_input_part1() {
+        let map = TopoMap::new(INPUT);
+        let mut trails = score_map(&map);
+        let test: usize = trails
+            .iter_mut()
+            .map(|e| {
+                e.points.sort();
+                e.points.dedup();
+                e.points.len()
+            })
+            .sum();
+        assert_eq!(test, 36);
+    }
+
+    #[test]
+    fn test_sample_input_part2() {
+        let map = TopoMap::new(INPUT);
+        let trails = score_map(&map);
+        let test: usize = trails
+            .iter()
+            .map(|e| {
+                e.points.len()
+            })
+            .sum();
+        assert_eq!(test, 81);
+    }
+}
@@ -0,0 +1,116 @@
+//! Day 11: Plutonian Pebbles. Count the stones after they change on every blink.
+
+use std::collections::HashMap;
+use std::ops::Mul;
+
+/// The rule a stone follows on the next blink.
+pub enum Next {
+    AddOne,
+    Split,
+    Multiply,
+}
+
+impl Next {
+    /// Pick the rule for a stone engraved with `num`.
+    pub fn from_val(num: u64) -> Next {
+        if num == 0 {
+            return Next::AddOne;
+        } else if ((num.checked_ilog10().unwrap_or(0)) + 1).is_multiple_of(2) {
+            return Next::Split;
+        }
+
+        Next::Multiply
+    }
+
+    /// The stones that replace `current`.
+    pub fn next_val(&self, current: &u64) ->  Vec<u64> {
+        match self {
+            Next::AddOne => vec!(current + 1),
+            Next::Multiply => vec!(current.mul(2024)),
+            Next::Split => {
+                let mut left = current.to_string();
+                let right = left.split_off(left.len() / 2);
+                vec!(left.parse().unwrap(), right.parse().unwrap())
+            }
+        }
+    }
+}
+
+/// Read the space separated stones.
+pub fn read_stones(input: &str) -> Vec<u64> {
+    input
+        .split_whitespace()
+        .map(|s| s.parse::<u64>().unwrap())
+        .collect()
+}
+
+/// Number of stones after blinking `steps` times.
+pub fn solve(input: &[u64], steps: u64) -> u64 {
+    let mut rocks: HashMap<u64, u64> = HashMap::new();
+
+    for i in input {
+        *rocks.entry(*i).or_insert(0) += 1;
+    }
+
+    for _step in 0..steps {
+        let to_process: Vec<(u64, u64)> = rocks.drain().collect::<Vec<(u64, u64)>>();
+        for rock in to_process {
+            let next = Next::from_val(rock.0);
+            let result = next.next_val(&rock.0);
+            for e in result {
+                *rocks.entry(e).or_insert(0) += rock.1;
+            }
+        }
+    }
+
+    rocks.values().sum()
+}
+
+/// Number of stones after blinking `steps` times, keeping every stone in a list.
+pub fn solve_naive(input: &[u64], steps: u64) -> u64 {
+    let mut rocks = input.to_vec();
+
+    for _step in 0..steps {
+        rocks = rocks
+            .iter()
+            .flat_map(|rock| Next::from_val(*rock).next_val(rock))
+            .collect();
+    }
+
+    rocks.len() as u64
+}
+
+/// Stones after 25 blinks.
+pub fn part_1(input: &str) -> u64 {
+    solve(&read_stones(input), 25)
+}
+
+/// Stones after 75 blinks.
+pub fn part_2(input: &str) -> u64 {
+    solve(&read_stones(input), 75)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn given_input_expect_solver_total() {
+        let input: Vec<u64> = vec![125, 17];
+        assert_eq!(solve(&input, 25), 55312);
+    }
+
+    #[test]
+    fn given_duplicate_stones_expect_both_counted() {
+        assert_eq!(solve(&read_stones("0 0"), 1), 2);
+        assert_eq!(part_1("125 17"), 55312);
+    }
+
+    #[test]
+    fn given_input_expect_naive_total_matches() {
+        let input: Vec<u64> = vec![125, 17];
+        assert_eq!(solve_naive(&input, 6), 22);
+        assert_eq!(solve_naive(&input, 25), solve(&input, 25));
+    }
+
+}
@@ -0,0 +1,327 @@
+//! Day 12: Garden Groups. Price the fences around regions of garden plots.
+
+use std::collections::HashSet;
+
+/// Crops stored row by row.
+#[derive(Debug)]
+pub struct FarmMap {
+    pub rows: usize,
+    pub columns: usize,
+    pub map: Vec<char>,
+}
+
+impl FarmMap {
+    /// Read the map from lines of crop letters.
+    pub fn new(input: &str) -> Self {
+        let mut iter = input.lines().peekable();
+        let columns: usize = iter.peek().unwrap().len();
+        let rows: usize = iter.count();
+        let map: Vec<char> = input.chars().filter(|c| !c.is_whitespace()).collect();
+
+        FarmMap { rows, columns, map }
+    }
+
+    /// Check if the row and column are on the map.
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        (0..self.rows).contains(&row) && (0..self.columns).contains(&col)
+    }
+
+    /// Crop at the row and column, `None` when outside the map.
+    pub fn get(&self, row: usize, col: usize) -> Option<char> {
+        if !self.contains(row, col) {
+            return None;
+        }
+
+        Some(self.map[row * self.columns + col])
+    }
+
+    /// Orthogonal neighbours of a position that are on the map.
+    pub fn get_neighbors(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let mut result = Vec::with_capacity(4);
+        let steps = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+        for (dr, dc) in steps {
+            let r = row.checked_add_signed(dr);
+            let c = col.checked_add_signed(dc);
+
+            if let (Some(r), Some(c)) = (r, c) {
+                if self.contains(r, c) {
+                    result.push((r, c));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// A connected area of plots growing the same crop.
+#[derive(Debug)]
+pub struct Region {
+    pub crop: char,
+    pub plots: HashSet<(usize, usize)>,
+    pub perimeter: u32,
+}
+
+impl Region {
+    /// Flood fill the region containing the row and column.
+    pub fn new(map: &FarmMap, row: usize, col: usize) -> Result<Self, String> {
+        let crop = map.get(row, col).ok_or("invalid row or col")?;
+        let mut region = Region {
+            crop,
+            plots: HashSet::new(),
+            perimeter: 0,
+        };
+        let mut queue: Vec<(usize, usize)> = vec![(row, col)];
+        let mut visited = HashSet::new();
+        while let Some((r, c)) = queue.pop() {
+            visited.insert((r, c));
+            let current = map.get(r, c).unwrap();
+
+            if current == crop {
+                region.add_plot(r, c);
+                let neighbors = map.get_neighbors(r, c);
+                for (r, c) in neighbors {
+                    if !visited.contains(&(r, c)) {
+                        queue.push((r, c));
+                        visited.insert((r, c));
+                    }
+                }
+            }
+        }
+
+        Ok(region)
+    }
+
+    /// Add a plot and update the perimeter for the neighbours it touches.
+    pub fn add_plot(&mut self, row: usize, col: usize) {
+        let neighbors = self.count_neighbors(row, col);
+
+        match neighbors {
+            0 => self.perimeter += 4,
+            1 => self.perimeter += 2,
+            2 => (),
+            3 => self.perimeter -= 2,
+            4 => self.perimeter -= 4,
+            _ => unreachable!(),
+        }
+
+        self.plots.insert((row, col));
+    }
+
+    /// Fence price using the perimeter.
+    pub fn price(&self) -> usize {
+        self.perimeter as usize * self.plots.len()
+    }
+
+    /// Fence price using the number of sides.
+    pub fn bulk_price(&self) -> usize {
+        self.count_corners() * self.plots.len()
+    }
+
+    /// Number of orthogonal neighbours already in the region.
+    pub fn count_neighbors(&self, row: usize, col: usize) -> u32 {
+        let steps = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+        let mut count = 0;
+        for (dr, dc) in steps {
+            let r = row.checked_add_signed(dr);
+            let c = col.checked_add_signed(dc);
+
+            if let (Some(r), Some(c)) = (r, c) {
+                if self.plots.contains(&(r, c)) {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Number of corners, which equals the number of sides.
+    pub fn count_corners(&self) -> usize {
+        let mut count = 0;
+
+        let checks = [
+            ((0, -1), (-1, -1), (-1, 0)),
+            ((-1, 0), (-1, 1), (0, 1)),
+            ((0, 1), (1, 1), (1, 0)),
+            ((1, 0), (1, -1), (0, -1))
+        ];
+
+        for plot in self.plots.iter() {
+            for check in checks.iter() {
+                let left = self.get_relative(*plot, check.0);
+                let middle = self.get_relative(*plot, check.1);
+                let right = self.get_relative(*plot, check.2);
+
+                if let (None, None) = (left, right) {
+                    count += 1;
+                }
+
+                if let (Some(_), None, Some(_)) = (left, middle, right) {
+                    count += 1;
+                }
+
+            }
+        }
+
+        count
+    }
+
+    /// The plot at an offset from another plot, if it is in the region.
+    pub fn get_relative(&self, plot: (usize, usize), step: (isize, isize)) -> Option<(usize, usize)> {
+
+        let r = plot.0.checked_add_signed(step.0);
+        let c = plot.1.checked_add_signed(step.1);
+
+        if let (Some(r), Some(c)) = (r, c) {
+            return self.plots.get(&(r, c)).copied();
+        }
+
+        None
+    }
+}
+
+/// Split the whole map into regions.
+pub fn read_regions(map: &FarmMap) -> Vec<Region> {
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut regions = Vec::new();
+
+    for i in 0..map.rows {
+        for j in 0..map.columns {
+            if !visited.contains(&(i, j)) {
+                if let Ok(region) = Region::new(map, i, j) {
+                    visited.extend(&region.plots);
+                    regions.push(region);
+                }
+            }
+        }
+    }
+
+    regions
+}
+
+/// Draw every region with its own label so separate regions of the same crop can be told apart,
+/// followed by a legend of each label's crop, area, perimeter and side count.
+pub fn render_regions(map: &FarmMap, regions: &[Region]) -> String {
+    let labels: Vec<char> = ('A'..='Z').chain('a'..='z').chain('0'..='9').collect();
+    let mut grid = vec![vec!['?'; map.columns]; map.rows];
+
+    for (i, region) in regions.iter().enumerate() {
+        for (r, c) in &region.plots {
+            grid[*r][*c] = labels[i % labels.len()];
+        }
+    }
+
+    let mut output: Vec<String> = grid.into_iter().map(|r| r.into_iter().collect()).collect();
+    output.push(String::new());
+
+    for (i, region) in regions.iter().enumerate() {
+        output.push(format!(
+            "{}: crop {} area {} perimeter {} sides {}",
+            labels[i % labels.len()],
+            region.crop,
+            region.plots.len(),
+            region.perimeter,
+            region.count_corners()
+        ));
+    }
+
+    output.join("\n")
+}
+
+/// Total fence price using perimeters.
+pub fn part_1(input: &str) -> usize {
+    let map = FarmMap::new(input);
+    read_regions(&map).iter().map(|r| r.price()).sum()
+}
+
+/// Total fence price using sides.
+pub fn part_2(input: &str) -> usize {
+    let map = FarmMap::new(input);
+    read_regions(&map).iter().map(|r| r.bulk_price()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIMPLE_INPUT: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+
+    const WITH_HOLES: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+
+    const COMPLEX: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn test_read_simple() {
+        let map = FarmMap::new(SIMPLE_INPUT);
+        let region = Region::new(&map, 1, 2).expect("Unable to create region");
+
+        assert_eq!(region.plots.len(), 4);
+        assert_eq!(region.perimeter, 10);
+    }
+
+    #[test]
+    fn test_with_holes() {
+        let map = FarmMap::new(WITH_HOLES);
+        let regions = read_regions(&map);
+
+        assert_eq!(regions.len(), 5);
+        assert_eq!(regions[0].perimeter, 36);
+        assert_eq!(regions.iter().map(|r| r.price()).sum::<usize>(), 772);
+    }
+
+    #[test]
+    fn test_complex_full() {
+        let map = FarmMap::new(COMPLEX);
+        let regions = read_regions(&map);
+
+        assert_eq!(regions.len(), 11);
+        assert_eq!(regions.iter().map(|r| r.price()).sum::<usize>(), 1930);
+    }
+
+    #[test]
+    fn snapshot_with_holes_regions() {
+        let map = FarmMap::new(WITH_HOLES);
+        let regions = read_regions(&map);
+
+        snapshot::assert_snapshot!("with_holes_regions", render_regions(&map, &regions));
+    }
+
+    #[test]
+    fn snapshot_complex_regions() {
+        let map = FarmMap::new(COMPLEX);
+        let regions = read_regions(&map);
+
+        snapshot::assert_snapshot!("complex_regions", render_regions(&map, &regions));
+    }
+
+    #[test]
+    fn test_complex_full_bulk() {
+        let map = FarmMap::new(COMPLEX);
+        let regions = read_regions(&map);
+
+        assert_eq!(regions.iter().map(|r| r.bulk_price()).sum::<usize>(), 1206);
+    }
+
+}
@@ -0,0 +1,227 @@
+//! Day 14: Restroom Redoubt. Predict where the security robots move.
+
+/// Width of the bathroom in the puzzle.
+pub const WIDTH: isize = 101;
+
+/// Height of the bathroom in the puzzle.
+pub const HEIGHT: isize = 103;
+
+/// Both robot variances need to drop below this for the robots to form a picture.
+pub const TREE_VARIANCE: f64 = 600.0;
+
+/// The robots moving around a room that wraps at its edges.
+pub struct Room {
+    pub height: isize,
+    pub width: isize,
+    pub robots: Vec<Robot>,
+}
+
+impl Room {
+    /// Create a room of the given size with a robot for every input line.
+    pub fn new(width: isize, height: isize, input: &str) -> Room {
+        Room {
+            height,
+            width,
+            robots: input
+                .lines()
+                .map(parse_line)
+                .collect::<Vec<Robot>>()
+        }
+    }
+
+    /// Move every robot `ticks` seconds.
+    pub fn tick(&mut self, ticks: isize) {
+        for r in &mut self.robots {
+            r.tick(self.width, self.height, ticks);
+        }
+    }
+
+    /// Product of the number of robots in each quadrant, ignoring the middle row and column.
+    pub fn safety_factor(&self) -> isize {
+        let mut zones = (0, 0, 0, 0);
+
+        for r in &self.robots {
+            if r.position.x < self.width / 2 && r.position.y < self.height / 2 {
+                zones.0 += 1;
+            } else if r.position.x > self.width / 2 && r.position.y < self.height / 2 {
+                zones.1 += 1;
+            } else if r.position.x < self.width / 2 && r.position.y > self.height / 2 {
+                zones.2 += 1;
+            } else if r.position.x > self.width / 2 && r.position.y > self.height / 2 {
+                zones.3 += 1;
+            }
+        }
+
+        zones.0 * zones.1 * zones.2 * zones.3
+    }
+
+    /// Number of robots at a position.
+    pub fn robots_at(&self, x: isize, y: isize) -> usize {
+        self.robots.iter().filter(|r| r.position.x == x && r.position.y == y ).count()
+    }
+
+    /// Variance of the robot x and y positions around the middle of the room.
+    pub fn variance(&self) -> (f64, f64) {
+        let avg_x = (self.width / 2) as f64;
+        let avg_y = (self.height / 2) as f64;
+        let mut var_x: f64 = 0.0;
+        let mut var_y: f64 = 0.0;
+
+        for r in &self.robots {
+            var_x += (r.position.x as f64 - avg_x).powi(2);
+            var_y += (r.position.y as f64 - avg_y).powi(2);
+        }
+
+        (var_x / self.robots.len() as f64, var_y / self.robots.len() as f64)
+    }
+
+    /// Tick until the robots gather close enough to form a picture, returning the ticks taken.
+    pub fn find_picture(&mut self, max_ticks: isize) -> Option<isize> {
+        for i in 0..max_ticks {
+            let (var_x, var_y) = self.variance();
+            if var_x < TREE_VARIANCE && var_y < TREE_VARIANCE {
+                return Some(i);
+            }
+            self.tick(1);
+        }
+
+        None
+    }
+
+    /// Draw the room with the number of robots at each position.
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let c = self.robots_at(x, y);
+                if c > 0 {
+                    output.push_str(&c.to_string());
+                } else {
+                    output.push(' ');
+                }
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// A robot position and its velocity per tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Robot {
+    pub position: Point,
+    pub vx: isize,
+    pub vy: isize,
+}
+
+impl Robot {
+    /// Move the robot `ticks` times, wrapping around the room.
+    pub fn tick(&mut self, room_width: isize, room_height: isize, ticks: isize) {
+        let x_vel_converted: isize = (self.vx % room_width) + room_width;
+        let y_vel_converted: isize = (self.vy % room_height) + room_height;
+
+        self.position.x = (self.position.x + x_vel_converted * ticks) % room_width;
+        self.position.y = (self.position.y + y_vel_converted * ticks) % room_height;
+    }
+}
+
+/// Read a `p=x,y v=x,y` robot line.
+pub fn parse_line(line: &str) -> Robot {
+    let parts: Vec<isize> = line
+        .split([' ', ',', '=', 'p', 'v'])
+        .filter(|&s| !s.is_empty())
+        .map(|p| p.parse::<isize>().unwrap())
+        .collect();
+
+    Robot {
+        position: Point {
+            x: parts[0],
+            y: parts[1],
+        },
+        vx: parts[2],
+        vy: parts[3],
+    }
+}
+
+/// A position in the room.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// Safety factor after 100 seconds.
+pub fn part_1(input: &str) -> isize {
+    let mut room = Room::new(WIDTH, HEIGHT, input);
+    room.tick(100);
+    room.safety_factor()
+}
+
+/// Seconds until the robots form a picture. The positions repeat after `WIDTH * HEIGHT` ticks.
+pub fn part_2(input: &str) -> Option<isize> {
+    let mut room = Room::new(WIDTH, HEIGHT, input);
+    room.find_picture(WIDTH * HEIGHT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse_line("p=0,4 v=3,-3"),
+            Robot {
+                position: Point { x: 0, y: 4 },
+                vx: 3,
+                vy: -3
+            }
+        );
+    }
+
+    #[test]
+    fn test_movement_robot_tick() {
+        let mut r = Robot {
+            position: Point { x: 6, y: 3 },
+            vx: -1,
+            vy: -3,
+        };
+        r.tick(7, 11, 1);
+        assert_eq!(r.position, Point { x: 5, y: 0 });
+    }
+
+    #[test]
+    fn test_sample_with_100_ticks() {
+        let mut room = Room::new(11, 7, INPUT);
+
+        room.tick(100);
+        assert_eq!(room.robots_at(6, 0), 2);
+        assert_eq!(room.robots_at(9, 0), 1);
+        assert_eq!(room.robots_at(0, 2), 1);
+        assert_eq!(room.robots_at(1, 3), 1);
+        assert_eq!(room.robots_at(2, 3), 1);
+        assert_eq!(room.robots_at(5, 4), 1);
+        assert_eq!(room.robots_at(3, 5), 1);
+        assert_eq!(room.robots_at(4, 5), 2);
+        assert_eq!(room.robots_at(1, 6), 1);
+        assert_eq!(room.robots_at(6, 6), 1);
+
+        println!("Robots: {:#?}", room.robots);
+        assert_eq!(room.safety_factor(), 12);
+    }
+
+}
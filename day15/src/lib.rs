@@ -0,0 +1,380 @@
+//! Day 15: Warehouse Woes. Follow the robot's moves as it pushes boxes around a warehouse.
+
+use std::collections::HashSet;
+
+pub use day6::Direction;
+
+/// Contents of a warehouse tile. Wide boxes take two tiles, a left and a right half.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Box,
+    BoxLeft,
+    BoxRight,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            '.' | '@' => Some(Tile::Empty),
+            '#' => Some(Tile::Wall),
+            'O' => Some(Tile::Box),
+            '[' => Some(Tile::BoxLeft),
+            ']' => Some(Tile::BoxRight),
+            _ => None,
+        }
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+            Tile::Box => 'O',
+            Tile::BoxLeft => '[',
+            Tile::BoxRight => ']',
+        }
+    }
+}
+
+/// Tiles stored row by row and the position of the robot.
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    pub rows: usize,
+    pub columns: usize,
+    pub tiles: Vec<Tile>,
+    pub robot: (usize, usize),
+}
+
+impl Warehouse {
+    /// Read the warehouse map. `@` marks the robot. Fails on rows narrower or wider than the first
+    /// and on characters that are not tiles.
+    pub fn new(input: &str) -> Result<Warehouse, String> {
+        let mut tiles = Vec::new();
+        let mut robot = (0, 0);
+        let mut rows = 0;
+        let columns = input.lines().next().map_or(0, |l| l.chars().count());
+
+        for (row, line) in input.lines().enumerate() {
+            let width = line.chars().count();
+            if width != columns {
+                return Err(format!("Row {} is {} tiles wide instead of {}", row + 1, width, columns));
+            }
+
+            for (col, c) in line.chars().enumerate() {
+                if c == '@' {
+                    robot = (row, col);
+                }
+                let tile = Tile::from_char(c)
+                    .ok_or(format!("Unknown tile '{}' in row {}, column {}", c, row + 1, col + 1))?;
+                tiles.push(tile);
+            }
+            rows += 1;
+        }
+
+        Ok(Warehouse { rows, columns, tiles, robot })
+    }
+
+    /// The warehouse with every tile twice as wide, for part 2.
+    pub fn widen(&self) -> Warehouse {
+        let tiles = self
+            .tiles
+            .iter()
+            .flat_map(|t| match t {
+                Tile::Box => [Tile::BoxLeft, Tile::BoxRight],
+                _ => [*t, *t],
+            })
+            .collect();
+
+        Warehouse {
+            rows: self.rows,
+            columns: self.columns * 2,
+            tiles,
+            robot: (self.robot.0, self.robot.1 * 2),
+        }
+    }
+
+    /// Tile at a position, `None` when outside the warehouse.
+    pub fn get(&self, pos: (usize, usize)) -> Option<Tile> {
+        if !(0..self.rows).contains(&pos.0) || !(0..self.columns).contains(&pos.1) {
+            return None;
+        }
+
+        Some(self.tiles[pos.0 * self.columns + pos.1])
+    }
+
+    fn set(&mut self, pos: (usize, usize), tile: Tile) {
+        self.tiles[pos.0 * self.columns + pos.1] = tile;
+    }
+
+    /// Boxes moved if the robot steps in `dir`, `None` when a wall blocks the push.
+    // Walk outwards from the robot collecting every box tile the push touches. Pushing a wide box
+    // up or down also pushes its other half, which can fan out into more boxes.
+    fn pushed_boxes(&self, dir: Direction) -> Option<Vec<(usize, usize)>> {
+        let vertical = matches!(dir, Direction::Up | Direction::Down);
+        let mut queue = vec![dir.step(self.robot.0, self.robot.1)?];
+        let mut seen = HashSet::new();
+        let mut boxes = Vec::new();
+
+        while let Some(pos) = queue.pop() {
+            if !seen.insert(pos) {
+                continue;
+            }
+
+            match self.get(pos)? {
+                Tile::Wall => return None,
+                Tile::Empty => continue,
+                Tile::Box => (),
+                Tile::BoxLeft => {
+                    if vertical {
+                        queue.push((pos.0, pos.1 + 1));
+                    }
+                }
+                Tile::BoxRight => {
+                    if vertical {
+                        queue.push((pos.0, pos.1 - 1));
+                    }
+                }
+            }
+
+            boxes.push(pos);
+            queue.push(dir.step(pos.0, pos.1)?);
+        }
+
+        Some(boxes)
+    }
+
+    /// Move the robot one step, pushing the boxes in front of it. Returns `false` when blocked.
+    pub fn step(&mut self, dir: Direction) -> bool {
+        let Some(boxes) = self.pushed_boxes(dir) else {
+            return false;
+        };
+
+        let moved: Vec<((usize, usize), Tile)> = boxes
+            .into_iter()
+            .map(|p| (p, self.get(p).unwrap()))
+            .collect();
+
+        for (pos, _) in moved.iter() {
+            self.set(*pos, Tile::Empty);
+        }
+
+        for (pos, tile) in moved {
+            self.set(dir.step(pos.0, pos.1).unwrap(), tile);
+        }
+
+        self.robot = dir.step(self.robot.0, self.robot.1).unwrap();
+        true
+    }
+
+    /// Follow every move in order.
+    pub fn run(&mut self, moves: &[Direction]) {
+        for dir in moves {
+            self.step(*dir);
+        }
+    }
+
+    /// Sum of `100 * row + column` of every box, measured from its left edge.
+    pub fn gps_sum(&self) -> usize {
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| matches!(t, Tile::Box | Tile::BoxLeft))
+            .map(|(i, _)| 100 * (i / self.columns) + i % self.columns)
+            .sum()
+    }
+
+    /// Draw the warehouse the same way as the puzzle input.
+    pub fn render(&self) -> String {
+        let mut output = Vec::with_capacity(self.rows);
+        for r in 0..self.rows {
+            let line: String = (0..self.columns)
+                .map(|c| {
+                    if (r, c) == self.robot {
+                        '@'
+                    } else {
+                        self.get((r, c)).unwrap().to_char()
+                    }
+                })
+                .collect();
+            output.push(line);
+        }
+        output.join("\n")
+    }
+}
+
+/// Read the moves, ignoring the line breaks between them.
+pub fn read_moves(input: &str) -> Vec<Direction> {
+    input.chars().filter_map(Direction::from_char).collect()
+}
+
+/// Split the input into the warehouse and the moves following the blank line.
+pub fn read_input(input: &str) -> Result<(Warehouse, Vec<Direction>), String> {
+    let (map, moves) = input
+        .split_once("\n\n")
+        .unwrap_or((input, ""));
+
+    Ok((Warehouse::new(map)?, read_moves(moves)))
+}
+
+/// GPS sum after the robot moves through the warehouse.
+pub fn part_1(input: &str) -> usize {
+    let (mut warehouse, moves) = read_input(input).expect("Invalid warehouse");
+    warehouse.run(&moves);
+    warehouse.gps_sum()
+}
+
+/// GPS sum after the robot moves through the wide warehouse.
+pub fn part_2(input: &str) -> usize {
+    let (warehouse, moves) = read_input(input).expect("Invalid warehouse");
+    let mut warehouse = warehouse.widen();
+    warehouse.run(&moves);
+    warehouse.gps_sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+    const SMALL_WIDE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+    const LARGE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    #[test]
+    fn given_small_example_expect_final_state() {
+        let (mut warehouse, moves) = read_input(SMALL).unwrap();
+        warehouse.run(&moves);
+
+        assert_eq!(
+            warehouse.render(),
+            "\
+########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########"
+        );
+        assert_eq!(warehouse.gps_sum(), 2028);
+    }
+
+    #[test]
+    fn given_large_example_expect_gps_sums() {
+        assert_eq!(part_1(LARGE), 10092);
+        assert_eq!(part_2(LARGE), 9021);
+    }
+
+    #[test]
+    fn given_large_example_expect_widened_map() {
+        let (warehouse, _) = read_input(LARGE).unwrap();
+
+        assert_eq!(
+            warehouse.widen().render(),
+            "\
+####################
+##....[]....[]..[]##
+##............[]..##
+##..[][]....[]..[]##
+##....[]@.....[]..##
+##[]##....[]......##
+##[]....[]....[]..##
+##..[][]..[]..[][]##
+##........[]......##
+####################"
+        );
+    }
+
+    #[test]
+    fn given_wide_boxes_expect_cascading_push() {
+        let (warehouse, moves) = read_input(SMALL_WIDE).unwrap();
+        let mut warehouse = warehouse.widen();
+        warehouse.run(&moves);
+
+        assert_eq!(
+            warehouse.render(),
+            "\
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############"
+        );
+        assert_eq!(warehouse.gps_sum(), 105 + 207 + 306);
+    }
+
+    #[test]
+    fn given_wall_behind_either_half_expect_push_blocked() {
+        let mut warehouse = Warehouse::new("########\n##.#..##\n##.[].##\n##.@..##\n########").unwrap();
+        let before = warehouse.render();
+
+        assert!(!warehouse.step(Direction::Up));
+        assert_eq!(warehouse.render(), before);
+
+        assert!(warehouse.step(Direction::Right));
+        assert!(!warehouse.step(Direction::Up));
+        assert_eq!(warehouse.get((2, 3)), Some(Tile::BoxLeft));
+        assert_eq!(warehouse.get((2, 4)), Some(Tile::BoxRight));
+    }
+
+    #[test]
+    fn snapshot_large_wide_final_state() {
+        let (warehouse, moves) = read_input(LARGE).unwrap();
+        let mut warehouse = warehouse.widen();
+        warehouse.run(&moves);
+
+        snapshot::assert_snapshot!("large_wide_final_state", warehouse.render());
+    }
+
+    #[test]
+    fn given_ragged_rows_or_unknown_tiles_expect_error() {
+        assert_eq!(Warehouse::new("####\n#@.\n####").unwrap_err(), "Row 2 is 3 tiles wide instead of 4");
+        assert_eq!(Warehouse::new("####\n#@x#\n####").unwrap_err(), "Unknown tile 'x' in row 2, column 3");
+        assert_eq!(Warehouse::new("####\n#@.#\n####").unwrap().columns, 4);
+    }
+}
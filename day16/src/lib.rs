@@ -0,0 +1,252 @@
+//! Day 16: Reindeer Maze. Find the cheapest routes through a maze where turning costs far more
+//! than stepping forward.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+pub use day6::Direction;
+
+/// Cost of a single step forward.
+pub const STEP_COST: u64 = 1;
+/// Cost of turning 90 degrees in place.
+pub const TURN_COST: u64 = 1000;
+
+/// Position and facing of the reindeer.
+pub type State = (usize, usize, Direction);
+
+/// The maze walls with the start and end tiles. The reindeer starts facing east.
+#[derive(Debug)]
+pub struct Maze {
+    pub rows: usize,
+    pub cols: usize,
+    pub walls: HashSet<(usize, usize)>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+/// Lowest score from start to end and every tile on a route with that score.
+#[derive(Debug)]
+pub struct BestPaths {
+    pub score: u64,
+    pub tiles: HashSet<(usize, usize)>,
+}
+
+impl Maze {
+    /// Read the maze. `S` marks the start and `E` the end.
+    pub fn new(input: &str) -> Maze {
+        let mut walls = HashSet::new();
+        let mut start = (0, 0);
+        let mut end = (0, 0);
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        walls.insert((row, col));
+                    }
+                    'S' => start = (row, col),
+                    'E' => end = (row, col),
+                    _ => (),
+                }
+            }
+            rows += 1;
+            cols = line.len();
+        }
+
+        Maze { rows, cols, walls, start, end }
+    }
+
+    fn is_open(&self, row: usize, col: usize) -> bool {
+        row < self.rows && col < self.cols && !self.walls.contains(&(row, col))
+    }
+
+    /// States reachable with a single move and the cost of each move.
+    pub fn get_adjacent(&self, state: State) -> Vec<(State, u64)> {
+        let (row, col, dir) = state;
+        let mut result = Vec::with_capacity(3);
+
+        if let Some((r, c)) = dir.step(row, col).filter(|(r, c)| self.is_open(*r, *c)) {
+            result.push(((r, c, dir), STEP_COST));
+        }
+        result.push(((row, col, dir.turn_left()), TURN_COST));
+        result.push(((row, col, dir.turn_right()), TURN_COST));
+
+        result
+    }
+
+    // Moves that lead into a state, the reverse of `get_adjacent`
+    fn get_previous(&self, state: State) -> Vec<(State, u64)> {
+        let (row, col, dir) = state;
+        let mut result = Vec::with_capacity(3);
+
+        if let Some((r, c)) = dir.turn_left().turn_left().step(row, col).filter(|(r, c)| self.is_open(*r, *c)) {
+            result.push(((r, c, dir), STEP_COST));
+        }
+        result.push(((row, col, dir.turn_left()), TURN_COST));
+        result.push(((row, col, dir.turn_right()), TURN_COST));
+
+        result
+    }
+
+    /// Lowest score to reach every state from the start, using Dijkstra's algorithm.
+    pub fn scores(&self) -> HashMap<State, u64> {
+        let start = (self.start.0, self.start.1, Direction::Right);
+        let mut scores = HashMap::from([(start, 0)]);
+        let mut queue = BinaryHeap::from([Reverse((0, start))]);
+
+        while let Some(Reverse((score, state))) = queue.pop() {
+            if scores.get(&state).is_some_and(|s| *s < score) {
+                continue;
+            }
+
+            for (next, cost) in self.get_adjacent(state) {
+                let next_score = score + cost;
+                if scores.get(&next).is_none_or(|s| next_score < *s) {
+                    scores.insert(next, next_score);
+                    queue.push(Reverse((next_score, next)));
+                }
+            }
+        }
+
+        scores
+    }
+
+    /// The lowest score and the tiles on any route achieving it, `None` when the end is unreachable.
+    // Walk backwards from the cheapest end states, only following moves whose cost accounts
+    // exactly for the score difference, so every state visited is on some best route.
+    pub fn best_paths(&self) -> Option<BestPaths> {
+        let scores = self.scores();
+        let score = Direction::ALL
+            .iter()
+            .filter_map(|d| scores.get(&(self.end.0, self.end.1, *d)))
+            .min()
+            .copied()?;
+
+        let mut stack: Vec<State> = Direction::ALL
+            .iter()
+            .map(|d| (self.end.0, self.end.1, *d))
+            .filter(|s| scores.get(s) == Some(&score))
+            .collect();
+        let mut seen: HashSet<State> = stack.iter().copied().collect();
+
+        while let Some(state) = stack.pop() {
+            let current = scores[&state];
+            for (previous, cost) in self.get_previous(state) {
+                if scores.get(&previous).is_some_and(|s| s + cost == current) && seen.insert(previous) {
+                    stack.push(previous);
+                }
+            }
+        }
+
+        let tiles = seen.into_iter().map(|(r, c, _)| (r, c)).collect();
+        Some(BestPaths { score, tiles })
+    }
+
+    /// Draw the maze with `O` on the given tiles.
+    pub fn render(&self, tiles: &HashSet<(usize, usize)>) -> String {
+        let mut output = Vec::with_capacity(self.rows);
+        for r in 0..self.rows {
+            let line: String = (0..self.cols)
+                .map(|c| {
+                    if self.walls.contains(&(r, c)) {
+                        '#'
+                    } else if (r, c) == self.start {
+                        'S'
+                    } else if (r, c) == self.end {
+                        'E'
+                    } else if tiles.contains(&(r, c)) {
+                        'O'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            output.push(line);
+        }
+        output.join("\n")
+    }
+}
+
+/// Lowest score to get from the start to the end.
+pub fn part_1(input: &str) -> u64 {
+    Maze::new(input).best_paths().expect("The maze has no path to the end").score
+}
+
+/// Number of tiles on at least one of the best routes.
+pub fn part_2(input: &str) -> usize {
+    Maze::new(input).best_paths().expect("The maze has no path to the end").tiles.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const SECOND: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[test]
+    fn given_examples_expect_lowest_scores() {
+        assert_eq!(part_1(FIRST), 7036);
+        assert_eq!(part_1(SECOND), 11048);
+    }
+
+    #[test]
+    fn given_examples_expect_best_path_tiles() {
+        assert_eq!(part_2(FIRST), 45);
+        assert_eq!(part_2(SECOND), 64);
+    }
+
+    #[test]
+    fn given_corridor_expect_turn_costs() {
+        assert_eq!(part_1("#####\n#S.E#\n#####"), 2);
+        assert_eq!(part_1("###\n#E#\n#.#\n#S#\n###"), 1002);
+    }
+
+    #[test]
+    fn given_walled_off_end_expect_none() {
+        assert!(Maze::new("#####\n#S#E#\n#####").best_paths().is_none());
+    }
+
+    #[test]
+    fn snapshot_first_example_best_paths() {
+        let maze = Maze::new(FIRST);
+        let best = maze.best_paths().unwrap();
+
+        snapshot::assert_snapshot!("first_best_paths", maze.render(&best.tiles));
+    }
+}
@@ -0,0 +1,12 @@
+fn main() {
+    let input = std::fs::read_to_string("input.txt").expect("Error reading input.txt");
+    let maze = day16::Maze::new(&input);
+    let best = maze.best_paths().expect("The maze has no path to the end");
+
+    if std::env::args().any(|a| a == "--render") {
+        println!("{}", maze.render(&best.tiles));
+    }
+
+    println!("Part 1: {}", best.score);
+    println!("Part 2: {}", best.tiles.len());
+}
@@ -0,0 +1,293 @@
+//! Day 17: Chronospatial Computer. Run programs on a 3-bit computer and find the register value
+//! that makes a program print itself.
+
+/// Steps a program may take before it is considered stuck in a loop.
+pub const MAX_STEPS: usize = 1_000_000;
+
+/// The eight instructions, numbered by their opcode.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    pub fn from_u8(value: u8) -> Option<Opcode> {
+        match value {
+            0 => Some(Opcode::Adv),
+            1 => Some(Opcode::Bxl),
+            2 => Some(Opcode::Bst),
+            3 => Some(Opcode::Jnz),
+            4 => Some(Opcode::Bxc),
+            5 => Some(Opcode::Out),
+            6 => Some(Opcode::Bdv),
+            7 => Some(Opcode::Cdv),
+            _ => None,
+        }
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    /// Whether the operand is a combo operand rather than a literal.
+    pub fn takes_combo(&self) -> bool {
+        matches!(self, Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv)
+    }
+}
+
+/// Registers, instruction pointer and output of the computer along with the program it runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cpu {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    pub ip: usize,
+    pub program: Vec<u8>,
+    pub output: Vec<u8>,
+}
+
+// Division by a power of two, zero once the shift is too large for the register
+fn shift(value: u64, by: u64) -> u64 {
+    u32::try_from(by).ok().and_then(|s| value.checked_shr(s)).unwrap_or(0)
+}
+
+impl Cpu {
+    pub fn new(a: u64, b: u64, c: u64, program: Vec<u8>) -> Cpu {
+        Cpu { a, b, c, ip: 0, program, output: Vec::new() }
+    }
+
+    /// Value of a combo operand: 0-3 are literals, 4-6 the registers A, B and C.
+    pub fn combo(&self, operand: u8) -> Result<u64, String> {
+        match operand {
+            0..=3 => Ok(operand as u64),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            _ => Err(format!("Invalid combo operand {} at {}", operand, self.ip)),
+        }
+    }
+
+    /// Run one instruction. Returns `false` once the program has halted.
+    pub fn step(&mut self) -> Result<bool, String> {
+        let (Some(&opcode), Some(&operand)) = (self.program.get(self.ip), self.program.get(self.ip + 1)) else {
+            return Ok(false);
+        };
+        let opcode = Opcode::from_u8(opcode).ok_or(format!("Invalid opcode {} at {}", opcode, self.ip))?;
+        let literal = operand as u64;
+
+        match opcode {
+            Opcode::Adv => self.a = shift(self.a, self.combo(operand)?),
+            Opcode::Bxl => self.b ^= literal,
+            Opcode::Bst => self.b = self.combo(operand)? % 8,
+            Opcode::Jnz => {
+                if self.a != 0 {
+                    self.ip = operand as usize;
+                    return Ok(true);
+                }
+            }
+            Opcode::Bxc => self.b ^= self.c,
+            Opcode::Out => self.output.push((self.combo(operand)? % 8) as u8),
+            Opcode::Bdv => self.b = shift(self.a, self.combo(operand)?),
+            Opcode::Cdv => self.c = shift(self.a, self.combo(operand)?),
+        }
+
+        self.ip += 2;
+        Ok(true)
+    }
+
+    /// Run until the program halts, failing if it takes more than `max_steps` instructions.
+    pub fn run(&mut self, max_steps: usize) -> Result<&[u8], String> {
+        for _ in 0..max_steps {
+            if !self.step()? {
+                return Ok(&self.output);
+            }
+        }
+
+        Err(format!("Program did not halt within {} steps", max_steps))
+    }
+
+    /// The output joined with commas, as the puzzle expects it.
+    pub fn output_string(&self) -> String {
+        self.output.iter().map(|o| o.to_string()).collect::<Vec<String>>().join(",")
+    }
+
+    /// The program as one instruction per line with what it does.
+    pub fn disassemble(&self) -> String {
+        let register = |operand: u8| match operand {
+            0..=3 => operand.to_string(),
+            4 => String::from("A"),
+            5 => String::from("B"),
+            6 => String::from("C"),
+            _ => String::from("?"),
+        };
+
+        self.program
+            .chunks_exact(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                let (opcode, operand) = (pair[0], pair[1]);
+                let Some(op) = Opcode::from_u8(opcode) else {
+                    return format!("{:>3}: ??? {}", i * 2, operand);
+                };
+                let arg = if op.takes_combo() { register(operand) } else { operand.to_string() };
+                let effect = match op {
+                    Opcode::Adv => format!("A = A >> {}", arg),
+                    Opcode::Bxl => format!("B = B ^ {}", arg),
+                    Opcode::Bst => format!("B = {} % 8", arg),
+                    Opcode::Jnz => format!("if A != 0 jump {}", arg),
+                    Opcode::Bxc => String::from("B = B ^ C"),
+                    Opcode::Out => format!("out {} % 8", arg),
+                    Opcode::Bdv => format!("B = A >> {}", arg),
+                    Opcode::Cdv => format!("C = A >> {}", arg),
+                };
+                format!("{:>3}: {} {:<2} ; {}", i * 2, op.mnemonic(), arg, effect)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Smallest value of register A making the program output itself, `None` if there is none.
+    // Programs of this kind print one value per loop and drop the low three bits of A each time,
+    // so the last value printed only depends on the top three bits. Build A three bits at a time
+    // from the end of the program, keeping every prefix that reproduces the tail so far.
+    pub fn find_quine(&self) -> Option<u64> {
+        self.find_quine_from(0, self.program.len())
+    }
+
+    fn find_quine_from(&self, prefix: u64, matched: usize) -> Option<u64> {
+        if matched == 0 {
+            return Some(prefix);
+        }
+
+        (0..8).find_map(|bits| {
+            let a = prefix.checked_mul(8)? | bits;
+            let mut cpu = Cpu::new(a, self.b, self.c, self.program.clone());
+            let tail = &self.program[matched - 1..];
+
+            match cpu.run(MAX_STEPS) {
+                Ok(output) if output == tail => self.find_quine_from(a, matched - 1),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Read the registers and the program.
+pub fn read_input(input: &str) -> Cpu {
+    let mut registers = [0; 3];
+    let mut program = Vec::new();
+
+    for line in input.lines() {
+        if let Some((name, value)) = line.strip_prefix("Register ").and_then(|l| l.split_once(": ")) {
+            let index = match name {
+                "A" => 0,
+                "B" => 1,
+                "C" => 2,
+                _ => panic!("Unknown register {}", name),
+            };
+            registers[index] = value.trim().parse().expect("Invalid register value");
+        } else if let Some(values) = line.strip_prefix("Program: ") {
+            program = values
+                .split(',')
+                .map(|v| v.trim().parse().expect("Invalid program value"))
+                .collect();
+        }
+    }
+
+    Cpu::new(registers[0], registers[1], registers[2], program)
+}
+
+/// Output of the program with the initial registers.
+pub fn part_1(input: &str) -> String {
+    let mut cpu = read_input(input);
+    cpu.run(MAX_STEPS).expect("Program failed");
+    cpu.output_string()
+}
+
+/// Lowest value of register A for which the program outputs itself.
+pub fn part_2(input: &str) -> Option<u64> {
+    read_input(input).find_quine()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    const QUINE: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+    fn run(a: u64, b: u64, c: u64, program: &[u8]) -> Cpu {
+        let mut cpu = Cpu::new(a, b, c, program.to_vec());
+        cpu.run(MAX_STEPS).unwrap();
+        cpu
+    }
+
+    #[test]
+    fn given_example_expect_output() {
+        assert_eq!(part_1(EXAMPLE), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn given_small_programs_expect_registers_and_output() {
+        assert_eq!(run(0, 0, 9, &[2, 6]).b, 1);
+        assert_eq!(run(10, 0, 0, &[5, 0, 5, 1, 5, 4]).output, vec![0, 1, 2]);
+
+        let cpu = run(2024, 0, 0, &[0, 1, 5, 4, 3, 0]);
+        assert_eq!(cpu.output_string(), "4,2,5,6,7,7,7,7,3,1,0");
+        assert_eq!(cpu.a, 0);
+
+        assert_eq!(run(0, 29, 0, &[1, 7]).b, 26);
+        assert_eq!(run(0, 2024, 43690, &[4, 0]).b, 44354);
+    }
+
+    #[test]
+    fn given_quine_example_expect_smallest_a() {
+        assert_eq!(part_2(QUINE), Some(117440));
+
+        assert_eq!(run(117440, 0, 0, &[0, 3, 5, 4, 3, 0]).output, vec![0, 3, 5, 4, 3, 0]);
+    }
+
+    #[test]
+    fn given_endless_loop_expect_step_limit() {
+        let mut cpu = Cpu::new(1, 0, 0, vec![3, 0]);
+        assert!(cpu.run(100).is_err());
+    }
+
+    #[test]
+    fn given_invalid_combo_operand_expect_error() {
+        let mut cpu = Cpu::new(1, 0, 0, vec![2, 7]);
+        assert!(cpu.run(MAX_STEPS).is_err());
+    }
+
+    #[test]
+    fn snapshot_quine_disassembly() {
+        snapshot::assert_snapshot!("quine_disassembly", read_input(QUINE).disassemble());
+    }
+}
@@ -0,0 +1,13 @@
+fn main() {
+    let input = std::fs::read_to_string("input.txt").expect("Error reading input.txt");
+
+    if std::env::args().any(|a| a == "--disassemble") {
+        println!("{}", day17::read_input(&input).disassemble());
+    }
+
+    println!("Part 1: {}", day17::part_1(&input));
+    match day17::part_2(&input) {
+        Some(a) => println!("Part 2: {}", a),
+        None => println!("Part 2: no value of A makes the program output itself"),
+    }
+}
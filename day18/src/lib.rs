@@ -0,0 +1,378 @@
+//! Day 18: RAM Run. Find the way out of a memory grid while bytes fall into it.
+
+use std::collections::{HashSet, VecDeque};
+
+/// Width and height of the memory grid.
+pub const SIZE: usize = 71;
+/// Bytes fallen before measuring the path in part 1.
+pub const FALLEN: usize = 1024;
+
+/// Square grid of memory with the corrupted positions. The exit is in the bottom right corner.
+#[derive(Debug, Clone)]
+pub struct MemoryGrid {
+    pub size: usize,
+    pub corrupted: Vec<bool>,
+}
+
+impl MemoryGrid {
+    /// Grid with the first bytes of the list corrupted. Bytes are `(x, y)`.
+    pub fn new(size: usize, bytes: &[(usize, usize)]) -> MemoryGrid {
+        let mut grid = MemoryGrid { size, corrupted: vec![false; size * size] };
+        for (x, y) in bytes {
+            grid.corrupt(*y, *x);
+        }
+        grid
+    }
+
+    /// Mark the row and column as corrupted. Positions off the grid are ignored.
+    pub fn corrupt(&mut self, row: usize, col: usize) {
+        if self.contains(row, col) {
+            self.corrupted[row * self.size + col] = true;
+        }
+    }
+
+    /// Check if the row and column are on the grid.
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        (0..self.size).contains(&row) && (0..self.size).contains(&col)
+    }
+
+    /// Check if the row and column are on the grid and not corrupted.
+    pub fn is_open(&self, row: usize, col: usize) -> bool {
+        self.contains(row, col) && !self.corrupted[row * self.size + col]
+    }
+
+    /// Orthogonal neighbours of a position that are on the grid and not corrupted.
+    pub fn get_adjacent(&self, row: usize, col: usize) -> Vec<(usize, usize)> {
+        let mut result = Vec::with_capacity(4);
+        let steps = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+        for (dr, dc) in steps {
+            let r = row.checked_add_signed(dr);
+            let c = col.checked_add_signed(dc);
+
+            if let (Some(r), Some(c)) = (r, c) {
+                if self.is_open(r, c) {
+                    result.push((r, c));
+                }
+            }
+        }
+
+        result
+    }
+
+    fn exit(&self) -> (usize, usize) {
+        (self.size - 1, self.size - 1)
+    }
+
+    /// Positions on a shortest path from the top left corner to the exit, both included.
+    pub fn shortest_path(&self) -> Option<Vec<(usize, usize)>> {
+        if !self.is_open(0, 0) {
+            return None;
+        }
+
+        let mut previous = vec![None; self.size * self.size];
+        let mut visited = HashSet::from([(0, 0)]);
+        let mut queue = VecDeque::from([(0, 0)]);
+
+        while let Some((r, c)) = queue.pop_front() {
+            if (r, c) == self.exit() {
+                let mut path = vec![(r, c)];
+                let mut current = (r, c);
+                while let Some(p) = previous[current.0 * self.size + current.1] {
+                    path.push(p);
+                    current = p;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for (nr, nc) in self.get_adjacent(r, c) {
+                if visited.insert((nr, nc)) {
+                    previous[nr * self.size + nc] = Some((r, c));
+                    queue.push_back((nr, nc));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Flood fill the positions reachable from the top left corner.
+    pub fn reachable(&self) -> HashSet<(usize, usize)> {
+        let mut visited = HashSet::new();
+        if !self.is_open(0, 0) {
+            return visited;
+        }
+
+        let mut queue = vec![(0, 0)];
+        visited.insert((0, 0));
+        while let Some((r, c)) = queue.pop() {
+            for (nr, nc) in self.get_adjacent(r, c) {
+                if visited.insert((nr, nc)) {
+                    queue.push((nr, nc));
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// Draw the grid with `#` for corrupted positions and `O` on the path.
+    pub fn render(&self, path: &[(usize, usize)]) -> String {
+        let path: HashSet<&(usize, usize)> = path.iter().collect();
+        let mut output = Vec::with_capacity(self.size);
+        for r in 0..self.size {
+            let line: String = (0..self.size)
+                .map(|c| {
+                    if !self.is_open(r, c) {
+                        '#'
+                    } else if path.contains(&(r, c)) {
+                        'O'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            output.push(line);
+        }
+        output.join("\n")
+    }
+}
+
+/// Disjoint sets over positions, with path halving and union by size.
+#[derive(Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind { parent: (0..len).collect(), sizes: vec![1; len] }
+    }
+
+    /// Representative of the set containing `x`.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merge the sets containing `a` and `b`.
+    pub fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.sizes[a] += self.sizes[b];
+    }
+}
+
+/// Read the `x,y` byte positions in the order they fall. Fails on a byte off a grid of `size`.
+pub fn read_bytes(input: &str, size: usize) -> Result<Vec<(usize, usize)>, String> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| {
+            let (x, y) = l.trim().split_once(',').ok_or(format!("Line {}: expected x,y", i + 1))?;
+            let parse = |v: &str| v.parse::<usize>().map_err(|_| format!("Line {}: invalid position '{}'", i + 1, v));
+            let (x, y) = (parse(x)?, parse(y)?);
+            if x >= size || y >= size {
+                return Err(format!("Line {}: {},{} is off the {}x{} grid", i + 1, x, y, size, size));
+            }
+            Ok((x, y))
+        })
+        .collect()
+}
+
+/// Steps to the exit after `fallen` bytes, `None` when it is cut off.
+pub fn steps_after(size: usize, bytes: &[(usize, usize)], fallen: usize) -> Option<usize> {
+    let grid = MemoryGrid::new(size, &bytes[..fallen.min(bytes.len())]);
+    grid.shortest_path().map(|p| p.len() - 1)
+}
+
+/// First byte that cuts off the exit, by binary search over the number of fallen bytes.
+pub fn first_blocking_byte(size: usize, bytes: &[(usize, usize)]) -> Option<(usize, usize)> {
+    let exit = (size - 1, size - 1);
+    let blocked = |n: usize| !MemoryGrid::new(size, &bytes[..n]).reachable().contains(&exit);
+
+    // Smallest number of fallen bytes blocking the exit
+    let (mut low, mut high) = (0, bytes.len());
+    if !blocked(high) {
+        return None;
+    }
+    while low < high {
+        let middle = (low + high) / 2;
+        if blocked(middle) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+
+    high.checked_sub(1).map(|i| bytes[i])
+}
+
+/// First byte that cuts off the exit, by removing the bytes in reverse order and joining the
+/// freed positions until the corners are connected again.
+pub fn first_blocking_byte_union_find(size: usize, bytes: &[(usize, usize)]) -> Option<(usize, usize)> {
+    let index = |r: usize, c: usize| r * size + c;
+    let exit = index(size - 1, size - 1);
+
+    // Bytes can land on the same position more than once, it only frees up once all are removed.
+    // Bytes off the grid never fall into it.
+    let on_grid = |&(x, y): &(usize, usize)| x < size && y < size;
+    let mut counts = vec![0; size * size];
+    for (x, y) in bytes.iter().filter(|b| on_grid(b)) {
+        counts[index(*y, *x)] += 1;
+    }
+
+    let mut grid = MemoryGrid::new(size, bytes);
+    let mut sets = UnionFind::new(size * size);
+    let join = |grid: &MemoryGrid, sets: &mut UnionFind, r: usize, c: usize| {
+        for (nr, nc) in grid.get_adjacent(r, c) {
+            sets.union(index(r, c), index(nr, nc));
+        }
+    };
+    let connected = |grid: &MemoryGrid, sets: &mut UnionFind| {
+        grid.is_open(0, 0) && grid.is_open(size - 1, size - 1) && sets.find(0) == sets.find(exit)
+    };
+
+    for r in 0..size {
+        for c in 0..size {
+            if grid.is_open(r, c) {
+                join(&grid, &mut sets, r, c);
+            }
+        }
+    }
+
+    if connected(&grid, &mut sets) {
+        return None;
+    }
+
+    for &(x, y) in bytes.iter().rev().filter(|b| on_grid(b)) {
+        counts[index(y, x)] -= 1;
+        if counts[index(y, x)] > 0 {
+            continue;
+        }
+
+        grid.corrupted[index(y, x)] = false;
+        join(&grid, &mut sets, y, x);
+        if connected(&grid, &mut sets) {
+            return Some((x, y));
+        }
+    }
+
+    None
+}
+
+fn format_byte(byte: Option<(usize, usize)>) -> String {
+    match byte {
+        Some((x, y)) => format!("{},{}", x, y),
+        None => String::from("none"),
+    }
+}
+
+/// Steps to the exit after the first kilobyte has fallen.
+pub fn part_1(input: &str) -> usize {
+    let bytes = read_bytes(input, SIZE).expect("Invalid bytes");
+    steps_after(SIZE, &bytes, FALLEN).expect("The exit is cut off")
+}
+
+/// Position of the first byte that cuts off the exit, as `x,y`.
+pub fn part_2(input: &str) -> String {
+    format_byte(first_blocking_byte(SIZE, &read_bytes(input, SIZE).expect("Invalid bytes")))
+}
+
+/// Same as part 2, using union-find instead of a binary search.
+pub fn part_2_union_find(input: &str) -> String {
+    format_byte(first_blocking_byte_union_find(SIZE, &read_bytes(input, SIZE).expect("Invalid bytes")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn given_example_expect_steps_after_twelve_bytes() {
+        assert_eq!(steps_after(7, &read_bytes(EXAMPLE, 7).unwrap(), 12), Some(22));
+    }
+
+    #[test]
+    fn given_example_expect_first_blocking_byte() {
+        let bytes = read_bytes(EXAMPLE, 7).unwrap();
+
+        assert_eq!(first_blocking_byte(7, &bytes), Some((6, 1)));
+        assert_eq!(first_blocking_byte_union_find(7, &bytes), Some((6, 1)));
+    }
+
+    #[test]
+    fn given_exit_never_blocked_expect_none() {
+        let bytes = [(1, 0), (1, 1), (2, 0)];
+
+        assert_eq!(first_blocking_byte(3, &bytes), None);
+        assert_eq!(first_blocking_byte_union_find(3, &bytes), None);
+    }
+
+    #[test]
+    fn given_repeated_byte_expect_both_searches_agree() {
+        let bytes = [(1, 0), (0, 1), (1, 0), (2, 2)];
+
+        assert_eq!(first_blocking_byte(3, &bytes), Some((0, 1)));
+        assert_eq!(first_blocking_byte_union_find(3, &bytes), Some((0, 1)));
+    }
+
+    #[test]
+    fn given_byte_off_the_grid_expect_it_ignored_or_rejected() {
+        // (3, 0) would land on (0, 1) if it wrapped into the next row
+        let bytes = [(3, 0), (0, 9), (1, 0), (1, 1), (0, 2)];
+
+        assert!(MemoryGrid::new(3, &bytes[..2]).corrupted.iter().all(|c| !c));
+        assert_eq!(first_blocking_byte(3, &bytes), Some((0, 2)));
+        assert_eq!(first_blocking_byte_union_find(3, &bytes), Some((0, 2)));
+        assert_eq!(read_bytes("1,1\n3,0\n", 3), Err(String::from("Line 2: 3,0 is off the 3x3 grid")));
+        assert!(read_bytes("1;1", 3).is_err());
+    }
+
+    #[test]
+    fn snapshot_example_path() {
+        let bytes = read_bytes(EXAMPLE, 7).unwrap();
+        let grid = MemoryGrid::new(7, &bytes[..12]);
+
+        snapshot::assert_snapshot!("example_path", grid.render(&grid.shortest_path().unwrap()));
+    }
+}
@@ -0,0 +1,149 @@
+//! Day 19: Linen Layout. Count the ways designs can be made from the available towel patterns.
+
+use std::collections::HashMap;
+
+/// Towel patterns stored as a trie, so every pattern starting a design is found in one walk.
+#[derive(Debug)]
+pub struct Trie {
+    children: Vec<HashMap<u8, usize>>,
+    terminal: Vec<bool>,
+}
+
+impl Trie {
+    pub fn new(patterns: &[&str]) -> Trie {
+        let mut trie = Trie { children: vec![HashMap::new()], terminal: vec![false] };
+        for pattern in patterns {
+            trie.insert(pattern);
+        }
+        trie
+    }
+
+    /// Add a pattern.
+    pub fn insert(&mut self, pattern: &str) {
+        let mut node = 0;
+        for b in pattern.bytes() {
+            node = match self.children[node].get(&b) {
+                Some(next) => *next,
+                None => {
+                    self.children.push(HashMap::new());
+                    self.terminal.push(false);
+                    let next = self.children.len() - 1;
+                    self.children[node].insert(b, next);
+                    next
+                }
+            };
+        }
+        self.terminal[node] = true;
+    }
+
+    /// Lengths of the patterns the text starts with, shortest first.
+    pub fn prefixes(&self, text: &[u8]) -> Vec<usize> {
+        let mut result = Vec::new();
+        let mut node = 0;
+        for (i, b) in text.iter().enumerate() {
+            match self.children[node].get(b) {
+                Some(next) => node = *next,
+                None => break,
+            }
+            if self.terminal[node] {
+                result.push(i + 1);
+            }
+        }
+        result
+    }
+
+    /// Number of distinct ways to arrange patterns into the design.
+    pub fn arrangements(&self, design: &str) -> u64 {
+        let mut memo: HashMap<usize, u64> = HashMap::new();
+        self.arrangements_from(design.as_bytes(), 0, &mut memo)
+    }
+
+    // Ways to build the suffix starting at `start`, remembered per start position
+    fn arrangements_from(&self, design: &[u8], start: usize, memo: &mut HashMap<usize, u64>) -> u64 {
+        if start == design.len() {
+            return 1;
+        }
+        if let Some(count) = memo.get(&start) {
+            return *count;
+        }
+
+        let count = self
+            .prefixes(&design[start..])
+            .into_iter()
+            .map(|len| self.arrangements_from(design, start + len, memo))
+            .sum();
+        memo.insert(start, count);
+        count
+    }
+}
+
+/// Read the patterns on the first line and the designs after the blank line.
+pub fn read_input(input: &str) -> (Vec<&str>, Vec<&str>) {
+    let (patterns, designs) = input.split_once("\n\n").unwrap_or((input, ""));
+    let patterns = patterns.split(',').map(|p| p.trim()).filter(|p| !p.is_empty()).collect();
+    let designs = designs.lines().map(|d| d.trim()).filter(|d| !d.is_empty()).collect();
+
+    (patterns, designs)
+}
+
+/// Number of designs that can be made at all.
+pub fn part_1(input: &str) -> usize {
+    let (patterns, designs) = read_input(input);
+    let trie = Trie::new(&patterns);
+    designs.iter().filter(|d| trie.arrangements(d) > 0).count()
+}
+
+/// Total number of ways every design can be made.
+pub fn part_2(input: &str) -> u64 {
+    let (patterns, designs) = read_input(input);
+    let trie = Trie::new(&patterns);
+    designs.iter().map(|d| trie.arrangements(d)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrwb";
+
+    #[test]
+    fn given_example_expect_possible_designs() {
+        assert_eq!(part_1(EXAMPLE), 6);
+    }
+
+    #[test]
+    fn given_example_expect_arrangements_per_design() {
+        let (patterns, designs) = read_input(EXAMPLE);
+        let trie = Trie::new(&patterns);
+        let counts: Vec<u64> = designs.iter().map(|d| trie.arrangements(d)).collect();
+
+        assert_eq!(counts, vec![2, 1, 4, 6, 0, 1, 2, 0]);
+        assert_eq!(part_2(EXAMPLE), 16);
+    }
+
+    #[test]
+    fn given_trie_expect_matching_prefixes() {
+        let trie = Trie::new(&["r", "rb", "rbg", "g"]);
+
+        assert_eq!(trie.prefixes(b"rbgr"), vec![1, 2, 3]);
+        assert_eq!(trie.prefixes(b"bg"), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn given_long_design_expect_count_without_blowup() {
+        let trie = Trie::new(&["a", "aa"]);
+
+        // Compositions of 60 into ones and twos, the 61st Fibonacci number
+        assert_eq!(trie.arrangements(&"a".repeat(60)), 2504730781961);
+    }
+}
@@ -0,0 +1,420 @@
+//! Day 2: Red-Nosed Reports. Check which reactor reports are safe.
+
+/// Parse a line of whitespace separated levels.
+pub fn read_report(line: &str) -> Vec<i32> {
+    line.split_whitespace()
+        .map(|s| s.parse::<i32>().unwrap())
+        .collect()
+}
+
+/// Indices of the steps that break the direction of the first step or change by more than 3.
+pub fn find_unsafe_steps(steps: &[i32]) -> Vec<usize> {
+    let distances: Vec<i32> = steps.windows(2).map(|e| e[1] - e[0]).collect();
+
+    let Some(sign) = distances.first().map(|d| d.signum()) else {
+        return Vec::new();
+    };
+
+    distances
+        .into_iter()
+        .enumerate()
+        .filter(|t| {
+            let val = sign * t.1;
+            val <= 0 || val > 3
+        })
+        .map(|t| t.0)
+        .collect()
+}
+
+/// Whether the levels of a report go up or down, taken from its first step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+}
+
+/// Why a step between two levels is unsafe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Violation {
+    /// The level did not change.
+    ZeroDelta,
+    /// The level moved against the direction of the report.
+    DirectionFlip,
+    /// The level changed by more than 3.
+    Jump,
+}
+
+/// An unsafe step from level `index` to level `index + 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Step {
+    pub index: usize,
+    pub delta: i32,
+    pub violation: Violation,
+}
+
+/// Why a report is safe or not.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Verdict {
+    /// `None` when the first step does not change the level, or there is no step.
+    pub direction: Option<Direction>,
+    /// The first unsafe step, the same as the first of [`find_unsafe_steps`].
+    pub first_violation: Option<Step>,
+    /// For an unsafe report, the first level the dampener can remove to make it safe.
+    pub dampener_removal: Option<usize>,
+}
+
+impl Verdict {
+    pub fn is_safe(&self) -> bool {
+        self.first_violation.is_none()
+    }
+
+    pub fn is_safe_with_dampener(&self) -> bool {
+        self.is_safe() || self.dampener_removal.is_some()
+    }
+
+    /// The verdict in words, with levels numbered from 1.
+    pub fn describe(&self, levels: &[i32]) -> String {
+        let Some(step) = self.first_violation else {
+            return String::from("safe");
+        };
+
+        let direction = match self.direction {
+            Some(Direction::Increasing) => "increasing",
+            Some(Direction::Decreasing) => "decreasing",
+            None => "no direction",
+        };
+        let problem = match step.violation {
+            Violation::ZeroDelta => String::from("do not change"),
+            Violation::DirectionFlip => String::from("flip direction"),
+            Violation::Jump => format!("jump by {}", step.delta.abs()),
+        };
+        let fix = match self.dampener_removal {
+            Some(i) => format!("removing level {} ({}) makes it safe", i + 1, levels[i]),
+            None => String::from("no single removal makes it safe"),
+        };
+
+        format!(
+            "{}, levels {} and {} ({} -> {}) {}; {}",
+            direction,
+            step.index + 1,
+            step.index + 2,
+            levels[step.index],
+            levels[step.index + 1],
+            problem,
+            fix
+        )
+    }
+}
+
+// The first unsafe step given the direction of the first step
+fn first_violation(steps: &[i32]) -> (Option<Direction>, Option<Step>) {
+    let deltas: Vec<i32> = steps.windows(2).map(|e| e[1] - e[0]).collect();
+    let sign = deltas.first().map_or(0, |d| d.signum());
+    let direction = match sign {
+        1 => Some(Direction::Increasing),
+        -1 => Some(Direction::Decreasing),
+        _ => None,
+    };
+
+    let violation = deltas.into_iter().enumerate().find_map(|(index, delta)| {
+        let violation = if delta == 0 {
+            Violation::ZeroDelta
+        } else if sign * delta < 0 || sign == 0 {
+            Violation::DirectionFlip
+        } else if delta.abs() > 3 {
+            Violation::Jump
+        } else {
+            return None;
+        };
+        Some(Step { index, delta, violation })
+    });
+
+    (direction, violation)
+}
+
+// Whether a step between two kept levels is safe in the given direction
+fn safe_step(from: i32, to: i32, increasing: bool) -> bool {
+    let delta = if increasing { to - from } else { from - to };
+    (1..=3).contains(&delta)
+}
+
+// Fewest removals to make the report safe in one direction, with the removed indices
+fn dampen_direction(steps: &[i32], k: usize, increasing: bool) -> (usize, Vec<usize>) {
+    let n = steps.len();
+    if n == 0 {
+        return (0, Vec::new());
+    }
+
+    // fewest[i] is the fewest removals after level i when it is kept, and next[i] the gap to the
+    // next kept level, `None` when the rest is removed. Kept neighbours are never more than k + 1
+    // apart, so each level only looks k + 1 levels ahead.
+    let mut fewest = vec![0; n];
+    let mut next: Vec<Option<usize>> = vec![None; n];
+    for i in (0..n).rev() {
+        fewest[i] = n - 1 - i;
+        for j in (i + 1..n).take(k + 1) {
+            let gap = j - i - 1;
+            // On ties, remove the earlier levels
+            if safe_step(steps[i], steps[j], increasing) && gap + fewest[j] <= fewest[i] {
+                fewest[i] = gap + fewest[j];
+                next[i] = Some(gap);
+            }
+        }
+    }
+
+    let first = (0..=k.min(n - 1)).rev().min_by_key(|s| s + fewest[*s]).unwrap();
+
+    let mut removed: Vec<usize> = (0..first).collect();
+    let mut i = first;
+    loop {
+        match next[i] {
+            Some(gap) => {
+                removed.extend(i + 1..i + 1 + gap);
+                i += 1 + gap;
+            }
+            None => {
+                removed.extend(i + 1..n);
+                break;
+            }
+        }
+    }
+
+    (removed.len(), removed)
+}
+
+/// Levels to remove to make the report safe with at most `k` removals, as few as possible and
+/// the earliest ones on ties. Empty when the report is already safe, `None` when `k` is not enough.
+/// Runs a DP over the kept levels in each direction, in O(n·k) and without copying the report.
+pub fn dampen(steps: &[i32], k: usize) -> Option<Vec<usize>> {
+    let (up, up_removed) = dampen_direction(steps, k, true);
+    let (down, down_removed) = dampen_direction(steps, k, false);
+
+    let (count, removed) = if down < up || (down == up && down_removed < up_removed) {
+        (down, down_removed)
+    } else {
+        (up, up_removed)
+    };
+    (count <= k).then_some(removed)
+}
+
+/// The first level the dampener can remove to make an unsafe report safe.
+pub fn dampener_removal(steps: &[i32]) -> Option<usize> {
+    dampen(steps, 1).and_then(|removed| removed.first().copied())
+}
+
+/// Judge a report, and when it is unsafe which removal the dampener could make.
+pub fn verdict(steps: &[i32]) -> Verdict {
+    let (direction, first_violation) = first_violation(steps);
+    let dampener_removal = first_violation.and_then(|_| dampener_removal(steps));
+
+    Verdict { direction, first_violation, dampener_removal }
+}
+
+/// Each unsafe report with its line number and verdict.
+pub fn explain_unsafe(input: &str) -> Vec<String> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .filter_map(|(i, l)| {
+            let levels = read_report(l);
+            let verdict = verdict(&levels);
+            (!verdict.is_safe()).then(|| format!("Line {}: {}: {}", i + 1, l.trim(), verdict.describe(&levels)))
+        })
+        .collect()
+}
+
+/// A report is safe when it has no unsafe steps.
+pub fn is_safe(steps: &[i32]) -> bool {
+    dampen(steps, 0).is_some()
+}
+
+/// A report is safe with the dampener when removing a single level makes it safe.
+pub fn is_safe_with_dampener(steps: &[i32]) -> bool {
+    dampen(steps, 1).is_some()
+}
+
+/// Number of reports that are safe after removing at most `k` levels.
+pub fn count_safe(input: &str, k: usize) -> usize {
+    input
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .filter(|r| dampen(&read_report(r), k).is_some())
+        .count()
+}
+
+/// Number of safe reports.
+pub fn part_1(input: &str) -> usize {
+    count_safe(input, 0)
+}
+
+/// Number of safe reports when the dampener is used.
+pub fn part_2(input: &str) -> usize {
+    count_safe(input, 1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST1: [&str; 6] = [
+        "7 6 4 2 1",
+        "1 2 7 8 9",
+        "9 7 6 2 1",
+        "1 3 2 4 5",
+        "8 6 4 4 1",
+        "1 3 6 7 9",
+    ];
+
+    #[test]
+    fn give_lines_validate_safe_check() {
+        // safe
+        assert!(is_safe(&read_report(TEST1[0])));
+        assert!(is_safe(&read_report(TEST1[5])));
+
+        // unsafe
+        assert!(!is_safe(&read_report(TEST1[1])));
+        assert!(!is_safe(&read_report(TEST1[2])));
+        assert!(!is_safe(&read_report(TEST1[3])));
+        assert!(!is_safe(&read_report(TEST1[4])));
+    }
+
+    #[test]
+    fn give_lines_validate_safe_check_with_dampener() {
+        // safe
+        assert!(is_safe_with_dampener(&read_report(TEST1[0])));
+        assert!(is_safe_with_dampener(&read_report(TEST1[3])));
+        assert!(is_safe_with_dampener(&read_report(TEST1[4])));
+        assert!(is_safe_with_dampener(&read_report(TEST1[5])));
+
+        // unsafe
+        assert!(!is_safe_with_dampener(&read_report(TEST1[1])));
+        assert!(!is_safe_with_dampener(&read_report(TEST1[2])));
+    }
+
+    #[test]
+    fn given_sample_input_count_safe_reports() {
+        let input = TEST1.join("\n");
+
+        assert_eq!(part_1(&input), 2);
+        assert_eq!(part_2(&input), 4);
+    }
+
+    #[test]
+    fn given_sample_reports_expect_verdicts() {
+        let verdicts: Vec<Verdict> = TEST1.iter().map(|l| verdict(&read_report(l))).collect();
+
+        assert!(verdicts[0].is_safe());
+        assert_eq!(verdicts[0].direction, Some(Direction::Decreasing));
+        assert_eq!(verdicts[0].dampener_removal, None);
+
+        assert_eq!(verdicts[1].first_violation, Some(Step { index: 1, delta: 5, violation: Violation::Jump }));
+        assert_eq!(verdicts[1].dampener_removal, None);
+        assert_eq!(verdicts[3].first_violation, Some(Step { index: 1, delta: -1, violation: Violation::DirectionFlip }));
+        assert_eq!(verdicts[3].dampener_removal, Some(1));
+        assert_eq!(verdicts[4].first_violation, Some(Step { index: 2, delta: 0, violation: Violation::ZeroDelta }));
+        assert_eq!(verdicts[4].dampener_removal, Some(2));
+
+        for (line, v) in TEST1.iter().zip(verdicts.iter()) {
+            let levels = read_report(line);
+            assert_eq!(v.is_safe(), is_safe(&levels));
+            assert_eq!(v.is_safe_with_dampener(), is_safe_with_dampener(&levels));
+            assert_eq!(v.first_violation.map(|s| s.index), find_unsafe_steps(&levels).first().copied());
+        }
+    }
+
+    // Fewest removals found by trying every subset of levels
+    fn brute_force(steps: &[i32], k: usize) -> Option<usize> {
+        (0u32..1 << steps.len())
+            .filter(|mask| mask.count_ones() as usize <= k)
+            .filter(|mask| {
+                let kept: Vec<i32> = (0..steps.len()).filter(|i| mask >> i & 1 == 0).map(|i| steps[i]).collect();
+                find_unsafe_steps(&kept).is_empty()
+            })
+            .map(|mask| mask.count_ones() as usize)
+            .min()
+    }
+
+    #[test]
+    fn given_sample_expect_removals() {
+        assert_eq!(dampen(&read_report(TEST1[0]), 1), Some(vec![]));
+        assert_eq!(dampen(&read_report(TEST1[1]), 1), None);
+        assert_eq!(dampen(&read_report(TEST1[1]), 2), Some(vec![0, 1]));
+        assert_eq!(dampen(&read_report(TEST1[3]), 1), Some(vec![1]));
+        assert_eq!(dampen(&read_report(TEST1[4]), 0), None);
+
+        let input = TEST1.join("\n");
+        assert_eq!(count_safe(&input, 0), part_1(&input));
+        assert_eq!(count_safe(&input, 1), part_2(&input));
+        assert_eq!(count_safe(&input, 2), 6);
+    }
+
+    #[test]
+    fn given_random_reports_expect_fewest_removals() {
+        let mut seed: u32 = 3;
+        let mut next = |m: u32| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) % m
+        };
+
+        for _ in 0..300 {
+            let len = 2 + next(8) as usize;
+            let mut level = 20;
+            let report: Vec<i32> = (0..len)
+                .map(|_| {
+                    level += next(9) as i32 - 3;
+                    level
+                })
+                .collect();
+
+            for k in 0..4 {
+                let removed = dampen(&report, k);
+                assert_eq!(removed.as_ref().map(|r| r.len()), brute_force(&report, k), "{:?} k={}", report, k);
+
+                if let Some(removed) = removed {
+                    let kept: Vec<i32> = (0..len).filter(|i| !removed.contains(i)).map(|i| report[i]).collect();
+                    assert!(find_unsafe_steps(&kept).is_empty(), "{:?} without {:?}", report, removed);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn given_short_reports_and_blank_lines_expect_safe_without_panics() {
+        for report in [&[][..], &[5]] {
+            assert!(is_safe(report));
+            assert!(is_safe_with_dampener(report));
+            assert!(find_unsafe_steps(report).is_empty());
+            assert_eq!(dampen(report, 0), Some(vec![]));
+        }
+        assert_eq!(dampen(&[3, 3], 0), None);
+        assert_eq!(dampen(&[3, 3], 1), Some(vec![0]));
+
+        let input = "1 2 3\n\n4 5 6\n";
+        assert_eq!(part_1(input), 2);
+        assert_eq!(part_1(input), count_safe(input, 0));
+    }
+
+    #[test]
+    fn given_flat_start_or_single_level_expect_no_direction() {
+        let flat = verdict(&[5, 5, 6]);
+        assert_eq!(flat.direction, None);
+        assert_eq!(flat.first_violation.map(|s| s.violation), Some(Violation::ZeroDelta));
+        assert_eq!(flat.dampener_removal, Some(0));
+
+        assert!(verdict(&[5]).is_safe());
+    }
+
+    #[test]
+    fn given_sample_input_explain_unsafe_reports() {
+        assert_eq!(
+            explain_unsafe(&TEST1.join("\n")),
+            vec![
+                "Line 2: 1 2 7 8 9: increasing, levels 2 and 3 (2 -> 7) jump by 5; no single removal makes it safe",
+                "Line 3: 9 7 6 2 1: decreasing, levels 3 and 4 (6 -> 2) jump by 4; no single removal makes it safe",
+                "Line 4: 1 3 2 4 5: increasing, levels 2 and 3 (3 -> 2) flip direction; removing level 2 (3) makes it safe",
+                "Line 5: 8 6 4 4 1: decreasing, levels 3 and 4 (4 -> 4) do not change; removing level 3 (4) makes it safe",
+            ]
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Day 20: Race Condition. Count the cheats through the walls of a race track that save time.
+
+use std::collections::{BTreeMap, HashSet};
+
+/// Longest cheat in part 1.
+pub const SHORT_CHEAT: usize = 2;
+/// Longest cheat in part 2.
+pub const LONG_CHEAT: usize = 20;
+/// Picoseconds a cheat has to save to be counted.
+pub const MIN_SAVING: usize = 100;
+
+/// The race track walls, start and end, and the single path between them.
+#[derive(Debug)]
+pub struct RaceTrack {
+    pub rows: usize,
+    pub cols: usize,
+    pub walls: HashSet<(usize, usize)>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+    pub path: Vec<(usize, usize)>,
+    // Picoseconds from the start to each position, `None` for walls
+    distances: Vec<Option<usize>>,
+}
+
+impl RaceTrack {
+    /// Picoseconds from the start to the position, `None` when it is not on the track.
+    pub fn distance(&self, row: usize, col: usize) -> Option<usize> {
+        if !(0..self.rows).contains(&row) || !(0..self.cols).contains(&col) {
+            return None;
+        }
+
+        self.distances[row * self.cols + col]
+    }
+
+    // Follow the track from the start, it never branches
+    fn trace(&mut self) {
+        let mut path = vec![self.start];
+        let mut previous = None;
+        let mut current = self.start;
+
+        while current != self.end {
+            let (row, col) = current;
+            let next = [(-1, 0), (0, 1), (1, 0), (0, -1)]
+                .iter()
+                .filter_map(|(dr, dc)| Some((row.checked_add_signed(*dr)?, col.checked_add_signed(*dc)?)))
+                .find(|p| Some(*p) != previous && p.0 < self.rows && p.1 < self.cols && !self.walls.contains(p))
+                .expect("The track ends before reaching the end");
+
+            previous = Some(current);
+            current = next;
+            path.push(current);
+        }
+
+        for (i, (row, col)) in path.iter().enumerate() {
+            self.distances[row * self.cols + col] = Some(i);
+        }
+        self.path = path;
+    }
+
+    /// How many cheats of up to `max_len` picoseconds save each amount of time.
+    // A cheat jumps between two track positions within `max_len` steps of each other. It saves
+    // the track distance between them minus the steps it takes.
+    pub fn cheats(&self, max_len: usize) -> BTreeMap<usize, usize> {
+        let mut savings = BTreeMap::new();
+        let reach = max_len as isize;
+
+        for (from, (row, col)) in self.path.iter().enumerate() {
+            for dr in -reach..=reach {
+                let spare = reach - dr.abs();
+                for dc in -spare..=spare {
+                    let steps = (dr.abs() + dc.abs()) as usize;
+                    let (Some(r), Some(c)) = (row.checked_add_signed(dr), col.checked_add_signed(dc)) else {
+                        continue;
+                    };
+                    let Some(to) = self.distance(r, c) else {
+                        continue;
+                    };
+
+                    if to > from + steps {
+                        *savings.entry(to - from - steps).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        savings
+    }
+
+    /// Number of cheats of up to `max_len` picoseconds saving at least `min_saving`.
+    pub fn count_cheats(&self, max_len: usize, min_saving: usize) -> usize {
+        self.cheats(max_len).range(min_saving..).map(|(_, count)| count).sum()
+    }
+}
+
+/// Read the track. `S` marks the start, `E` the end and `#` the walls. An empty input gives an
+/// empty map without a path.
+pub fn init_map(input: Vec<&str>) -> RaceTrack {
+    let mut walls: HashSet<(usize, usize)> = HashSet::new();
+    let rows = input.len();
+    let cols = input.first().map_or(0, |line| line.len());
+    let mut start = (0, 0);
+    let mut end = (0, 0);
+
+    input.iter().enumerate().for_each(|(row, line)| {
+        line.chars().enumerate().for_each(|(col, chr)| match chr {
+            'S' => start = (row, col),
+            'E' => end = (row, col),
+            '#' => {
+                walls.insert((row, col));
+            }
+            _ => (),
+        });
+    });
+
+    let mut track = RaceTrack {
+        rows,
+        cols,
+        walls,
+        start,
+        end,
+        path: Vec::new(),
+        distances: vec![None; rows * cols],
+    };
+    if rows > 0 {
+        track.trace();
+    }
+    track
+}
+
+/// Cheats of up to 2 picoseconds saving at least 100.
+pub fn part_1(input: &str) -> usize {
+    init_map(input.lines().collect()).count_cheats(SHORT_CHEAT, MIN_SAVING)
+}
+
+/// Cheats of up to 20 picoseconds saving at least 100.
+pub fn part_2(input: &str) -> usize {
+    init_map(input.lines().collect()).count_cheats(LONG_CHEAT, MIN_SAVING)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    #[test]
+    fn given_example_expect_track_length() {
+        let track = init_map(EXAMPLE.lines().collect());
+
+        assert_eq!(track.path.len() - 1, 84);
+        assert_eq!(track.distance(track.end.0, track.end.1), Some(84));
+        assert_eq!(track.distance(0, 0), None);
+    }
+
+    #[test]
+    fn given_short_cheats_expect_savings() {
+        let track = init_map(EXAMPLE.lines().collect());
+        let expected = BTreeMap::from([
+            (2, 14),
+            (4, 14),
+            (6, 2),
+            (8, 4),
+            (10, 2),
+            (12, 3),
+            (20, 1),
+            (36, 1),
+            (38, 1),
+            (40, 1),
+            (64, 1),
+        ]);
+
+        assert_eq!(track.cheats(SHORT_CHEAT), expected);
+    }
+
+    #[test]
+    fn given_long_cheats_expect_count_over_threshold() {
+        let track = init_map(EXAMPLE.lines().collect());
+        let savings = track.cheats(LONG_CHEAT);
+
+        assert_eq!(savings[&50], 32);
+        assert_eq!(savings[&76], 3);
+        assert_eq!(track.count_cheats(LONG_CHEAT, 50), 285);
+        assert_eq!(track.count_cheats(SHORT_CHEAT, 20), 5);
+    }
+
+    #[test]
+    fn given_empty_input_expect_empty_map() {
+        let track = init_map(Vec::new());
+
+        assert!(track.path.is_empty());
+        assert_eq!(track.distance(0, 0), None);
+        assert_eq!(part_1(""), 0);
+    }
+}
@@ -0,0 +1,273 @@
+//! Day 21: Keypad Conundrum. Type door codes through a chain of robots pressing directional
+//! keypads.
+
+use std::collections::HashMap;
+
+pub use day6::Direction;
+
+/// Directional keypad robots between the person and the door in part 1.
+pub const SHORT_CHAIN: usize = 2;
+/// Directional keypad robots between the person and the door in part 2.
+pub const LONG_CHAIN: usize = 25;
+
+const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+
+/// Key positions of a keypad and the gap no robot arm may point at.
+#[derive(Debug)]
+pub struct Keypad {
+    keys: HashMap<char, (usize, usize)>,
+    gap: (usize, usize),
+}
+
+impl Keypad {
+    fn new(layout: &[&str]) -> Keypad {
+        let mut keys = HashMap::new();
+        let mut gap = (0, 0);
+        for (row, line) in layout.iter().enumerate() {
+            for (col, key) in line.chars().enumerate() {
+                if key == ' ' {
+                    gap = (row, col);
+                } else {
+                    keys.insert(key, (row, col));
+                }
+            }
+        }
+        Keypad { keys, gap }
+    }
+
+    /// The door keypad with the digits and `A`.
+    pub fn numeric() -> Keypad {
+        Keypad::new(&NUMERIC)
+    }
+
+    /// The robot keypad with the `^>v<` arrows and `A`.
+    pub fn directional() -> Keypad {
+        Keypad::new(&DIRECTIONAL)
+    }
+
+    /// Arrow presses worth considering to move from one key to another, each ending with `A`.
+    // Only the two L shaped paths can be shortest once robots type them, zig-zagging costs an
+    // extra change of direction. Drop the ones that pass over the gap.
+    pub fn paths(&self, from: char, to: char) -> Vec<String> {
+        let (r1, c1) = self.keys[&from];
+        let (r2, c2) = self.keys[&to];
+
+        let vertical = if r2 < r1 { Direction::Up } else { Direction::Down };
+        let horizontal = if c2 < c1 { Direction::Left } else { Direction::Right };
+        let vertical = vec![vertical; r1.abs_diff(r2)];
+        let horizontal = vec![horizontal; c1.abs_diff(c2)];
+
+        let mut result: Vec<String> = Vec::with_capacity(2);
+        for moves in [[&horizontal[..], &vertical[..]].concat(), [&vertical[..], &horizontal[..]].concat()] {
+            let mut pos = (r1, c1);
+            let crosses_gap = moves.iter().any(|dir| {
+                pos = dir.step(pos.0, pos.1).unwrap();
+                pos == self.gap
+            });
+
+            let presses: String = moves.iter().map(|d| d.to_char()).chain(['A']).collect();
+            if !crosses_gap && !result.contains(&presses) {
+                result.push(presses);
+            }
+        }
+        result
+    }
+}
+
+/// A numeric keypad behind a chain of directional keypad robots, remembering the cost of each
+/// move at each depth of the chain.
+#[derive(Debug)]
+pub struct KeypadChain {
+    pub robots: usize,
+    numeric: Keypad,
+    directional: Keypad,
+    memo: HashMap<(char, char, usize), u64>,
+}
+
+impl KeypadChain {
+    pub fn new(robots: usize) -> KeypadChain {
+        KeypadChain {
+            robots,
+            numeric: Keypad::numeric(),
+            directional: Keypad::directional(),
+            memo: HashMap::new(),
+        }
+    }
+
+    /// Presses needed to make the robot `depth` keypads down move from one key to another
+    /// and press it. At depth 0 the person presses the key directly.
+    pub fn move_cost(&mut self, from: char, to: char, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        if let Some(cost) = self.memo.get(&(from, to, depth)) {
+            return *cost;
+        }
+
+        let cost = self
+            .directional
+            .paths(from, to)
+            .iter()
+            .map(|p| self.sequence_cost(p, depth - 1))
+            .min()
+            .unwrap();
+        self.memo.insert((from, to, depth), cost);
+        cost
+    }
+
+    // Presses needed to type the sequence on the keypad at `depth`, every arm starts on `A`
+    fn sequence_cost(&mut self, sequence: &str, depth: usize) -> u64 {
+        let keys: Vec<char> = sequence.chars().collect();
+        std::iter::once('A')
+            .chain(keys.iter().copied())
+            .zip(keys.iter().copied())
+            .map(|(from, to)| self.move_cost(from, to, depth))
+            .sum()
+    }
+
+    /// Fewest presses the person makes to type the code on the door.
+    pub fn cost(&mut self, code: &str) -> u64 {
+        let mut from = 'A';
+        let mut total = 0;
+        for to in code.chars() {
+            total += self
+                .numeric
+                .paths(from, to)
+                .iter()
+                .map(|p| self.sequence_cost(p, self.robots))
+                .min()
+                .unwrap();
+            from = to;
+        }
+        total
+    }
+
+    /// One of the shortest press sequences for the code. Its length grows exponentially with
+    /// the number of robots, so this is only useful for short chains.
+    pub fn presses(&mut self, code: &str) -> String {
+        let mut from = 'A';
+        let mut result = String::new();
+        for to in code.chars() {
+            let best = self
+                .numeric
+                .paths(from, to)
+                .into_iter()
+                .min_by_key(|p| self.sequence_cost(p, self.robots))
+                .unwrap();
+            result.push_str(&self.expand(&best, self.robots));
+            from = to;
+        }
+        result
+    }
+
+    // Cheapest presses for the sequence on the keypad at `depth`, expanded down to the person
+    fn expand(&mut self, sequence: &str, depth: usize) -> String {
+        if depth == 0 {
+            return sequence.to_string();
+        }
+
+        let mut from = 'A';
+        let mut result = String::new();
+        for to in sequence.chars() {
+            let best = self
+                .directional
+                .paths(from, to)
+                .into_iter()
+                .min_by_key(|p| self.sequence_cost(p, depth - 1))
+                .unwrap();
+            result.push_str(&self.expand(&best, depth - 1));
+            from = to;
+        }
+        result
+    }
+}
+
+/// Presses for the code multiplied by its numeric part.
+pub fn complexity(chain: &mut KeypadChain, code: &str) -> u64 {
+    let number: u64 = code.trim_end_matches('A').parse().expect("Code should be digits before A");
+    chain.cost(code) * number
+}
+
+fn total_complexity(input: &str, robots: usize) -> u64 {
+    let mut chain = KeypadChain::new(robots);
+    input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|code| complexity(&mut chain, code))
+        .sum()
+}
+
+/// Sum of the complexities with two robots in the chain.
+pub fn part_1(input: &str) -> u64 {
+    total_complexity(input, SHORT_CHAIN)
+}
+
+/// Sum of the complexities with twenty five robots in the chain.
+pub fn part_2(input: &str) -> u64 {
+    total_complexity(input, LONG_CHAIN)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "029A\n980A\n179A\n456A\n379A";
+
+    #[test]
+    fn given_example_expect_complexities() {
+        assert_eq!(part_1(EXAMPLE), 126384);
+        assert_eq!(part_2(EXAMPLE), 154115708116294);
+    }
+
+    #[test]
+    fn given_example_codes_expect_press_counts() {
+        let mut chain = KeypadChain::new(SHORT_CHAIN);
+        let costs: Vec<u64> = EXAMPLE.lines().map(|c| chain.cost(c)).collect();
+
+        assert_eq!(costs, vec![68, 60, 68, 64, 64]);
+    }
+
+    #[test]
+    fn given_no_robots_expect_direct_presses() {
+        let mut chain = KeypadChain::new(0);
+
+        assert_eq!(chain.cost("029A"), 12);
+        assert_eq!(chain.presses("029A").len(), 12);
+    }
+
+    #[test]
+    fn given_gap_expect_paths_avoid_it() {
+        assert_eq!(Keypad::numeric().paths('A', '1'), vec![String::from("^<<A")]);
+        assert_eq!(Keypad::numeric().paths('7', '0'), vec![String::from(">vvvA")]);
+        assert_eq!(Keypad::directional().paths('<', 'A'), vec![String::from(">>^A")]);
+        assert_eq!(Keypad::directional().paths('A', 'A'), vec![String::from("A")]);
+    }
+
+    #[test]
+    fn given_press_sequence_expect_it_types_the_code() {
+        let mut chain = KeypadChain::new(SHORT_CHAIN);
+        let presses = chain.presses("029A");
+        assert_eq!(presses.len() as u64, chain.cost("029A"));
+
+        // Replay the presses through each keypad in the chain
+        let mut typed = presses;
+        for layout in [&DIRECTIONAL[..], &DIRECTIONAL[..], &NUMERIC[..]] {
+            let keypad = Keypad::new(layout);
+            let keys: HashMap<(usize, usize), char> = keypad.keys.iter().map(|(k, p)| (*p, *k)).collect();
+            let mut pos = keypad.keys[&'A'];
+            let mut output = String::new();
+            for press in typed.chars() {
+                match Direction::from_char(press) {
+                    Some(dir) => pos = dir.step(pos.0, pos.1).unwrap(),
+                    None => output.push(keys[&pos]),
+                }
+                assert_ne!(pos, keypad.gap);
+            }
+            typed = output;
+        }
+
+        assert_eq!(typed, "029A");
+    }
+}
@@ -0,0 +1,13 @@
+fn main() {
+    let input = std::fs::read_to_string("input.txt").expect("Error reading input.txt");
+
+    if std::env::args().any(|a| a == "--presses") {
+        let mut chain = day21::KeypadChain::new(day21::SHORT_CHAIN);
+        for code in input.lines() {
+            println!("{}: {}", code, chain.presses(code));
+        }
+    }
+
+    println!("Part 1: {}", day21::part_1(&input));
+    println!("Part 2: {}", day21::part_2(&input));
+}
@@ -0,0 +1,144 @@
+//! Day 22: Monkey Market. Predict the buyers' secret numbers and pick the price changes to sell on.
+
+/// Secret numbers each buyer generates in a day.
+pub const STEPS: usize = 2000;
+
+const PRUNE: u64 = 16777216;
+// Price changes range from -9 to 9, four of them make a base 19 number
+const BASE: usize = 19;
+const SEQUENCES: usize = BASE * BASE * BASE * BASE;
+
+/// The secret number following `secret`.
+pub fn next_secret(secret: u64) -> u64 {
+    let secret = ((secret << 6) ^ secret) % PRUNE;
+    let secret = ((secret >> 5) ^ secret) % PRUNE;
+    ((secret << 11) ^ secret) % PRUNE
+}
+
+/// The secret numbers a buyer generates after an initial secret. It never ends, take as many as
+/// needed.
+#[derive(Debug, Clone)]
+pub struct Secrets {
+    secret: u64,
+}
+
+impl Secrets {
+    pub fn new(initial: u64) -> Secrets {
+        Secrets { secret: initial }
+    }
+}
+
+impl Iterator for Secrets {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        self.secret = next_secret(self.secret);
+        Some(self.secret)
+    }
+}
+
+/// Prices offered by a buyer, the last digit of the initial secret and each of the next `steps`.
+pub fn prices(initial: u64, steps: usize) -> impl Iterator<Item = i8> {
+    std::iter::once(initial).chain(Secrets::new(initial).take(steps)).map(|s| (s % 10) as i8)
+}
+
+/// Base 19 key of four price changes.
+pub fn encode(changes: [i8; 4]) -> usize {
+    changes.iter().fold(0, |key, c| key * BASE + (c + 9) as usize)
+}
+
+/// The four price changes of a key.
+pub fn decode(mut key: usize) -> [i8; 4] {
+    let mut changes = [0; 4];
+    for c in changes.iter_mut().rev() {
+        *c = (key % BASE) as i8 - 9;
+        key /= BASE;
+    }
+    changes
+}
+
+/// The four price changes earning the most bananas across the buyers, and how many it earns.
+// Every buyer sells the first time a sequence shows up, so remember per sequence the last buyer
+// that sold on it and total the prices in a flat array indexed by the sequence key.
+pub fn best_sequence(initials: &[u64], steps: usize) -> ([i8; 4], u64) {
+    let mut totals = vec![0u64; SEQUENCES];
+    let mut last_buyer = vec![usize::MAX; SEQUENCES];
+
+    for (buyer, initial) in initials.iter().enumerate() {
+        let mut key = 0;
+        let mut previous = None;
+        for (i, price) in prices(*initial, steps).enumerate() {
+            if let Some(previous) = previous {
+                key = (key * BASE + (price - previous + 9) as usize) % SEQUENCES;
+                if i >= 4 && last_buyer[key] != buyer {
+                    last_buyer[key] = buyer;
+                    totals[key] += price as u64;
+                }
+            }
+            previous = Some(price);
+        }
+    }
+
+    let (key, total) = totals
+        .iter()
+        .enumerate()
+        .max_by_key(|(key, total)| (**total, std::cmp::Reverse(*key)))
+        .unwrap();
+    (decode(key), *total)
+}
+
+/// Read the initial secret of each buyer.
+pub fn read_secrets(input: &str) -> Vec<u64> {
+    input.split_whitespace().map(|s| s.parse::<u64>().unwrap()).collect()
+}
+
+/// Sum of every buyer's 2000th secret number.
+pub fn part_1(input: &str) -> u64 {
+    read_secrets(input).iter().map(|s| Secrets::new(*s).nth(STEPS - 1).unwrap()).sum()
+}
+
+/// Most bananas a single sequence of four price changes can earn.
+pub fn part_2(input: &str) -> u64 {
+    best_sequence(&read_secrets(input), STEPS).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_secret_123_expect_next_ten() {
+        let secrets: Vec<u64> = Secrets::new(123).take(10).collect();
+
+        assert_eq!(
+            secrets,
+            vec![15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432, 5908254]
+        );
+    }
+
+    #[test]
+    fn given_example_expect_sum_of_2000th_secrets() {
+        assert_eq!(Secrets::new(1).nth(STEPS - 1), Some(8685429));
+        assert_eq!(part_1("1\n10\n100\n2024"), 37327623);
+    }
+
+    #[test]
+    fn given_example_expect_best_sequence() {
+        assert_eq!(best_sequence(&[1, 2, 3, 2024], STEPS), ([-2, 1, -1, 3], 23));
+        assert_eq!(part_2("1\n2\n3\n2024"), 23);
+    }
+
+    #[test]
+    fn given_price_changes_expect_key_round_trip() {
+        for changes in [[-9, -9, -9, -9], [9, 9, 9, 9], [-2, 1, -1, 3], [0, 0, 0, 0]] {
+            assert_eq!(decode(encode(changes)), changes);
+        }
+        assert_eq!(encode([9, 9, 9, 9]), SEQUENCES - 1);
+    }
+
+    #[test]
+    fn given_secret_123_expect_first_sequence_price() {
+        // Changes -1, -1, 0, 2 first bring the price to 6
+        assert_eq!(best_sequence(&[123], 9), ([-1, -1, 0, 2], 6));
+    }
+}
@@ -0,0 +1,6 @@
+fn main() {
+    let input = std::fs::read_to_string("input.txt").expect("Error reading input.txt");
+
+    println!("Part 1: {}", day22::part_1(&input));
+    println!("Part 2: {}", day22::part_2(&input));
+}
@@ -0,0 +1,195 @@
+//! Day 23: LAN Party. Find groups of computers that are all connected to each other.
+
+use std::collections::{HashMap, HashSet};
+
+/// Undirected graph with string node ids.
+// each node maps to every node it shares an edge with, in both directions
+#[derive(Debug, Default)]
+pub struct Graph {
+    pub edges: HashMap<String, HashSet<String>>,
+}
+
+impl Graph {
+    pub fn new() -> Graph {
+        Graph::default()
+    }
+
+    /// Connect two nodes, adding them if they are new.
+    pub fn add_edge(&mut self, a: &str, b: &str) {
+        self.edges.entry(a.to_string()).or_default().insert(b.to_string());
+        self.edges.entry(b.to_string()).or_default().insert(a.to_string());
+    }
+
+    /// Nodes sharing an edge with the node, empty when it is not in the graph.
+    pub fn neighbors(&self, node: &str) -> HashSet<String> {
+        self.edges.get(node).cloned().unwrap_or_default()
+    }
+
+    /// Check if two nodes share an edge.
+    pub fn connected(&self, a: &str, b: &str) -> bool {
+        self.edges.get(a).is_some_and(|n| n.contains(b))
+    }
+
+    /// Every set of three nodes connected to each other, each sorted, in sorted order.
+    pub fn triangles(&self) -> Vec<[&str; 3]> {
+        let mut result = Vec::new();
+        for (a, neighbors) in self.edges.iter() {
+            for b in neighbors.iter().filter(|b| *b > a) {
+                for c in neighbors.iter().filter(|c| *c > b) {
+                    if self.connected(b, c) {
+                        result.push([a.as_str(), b.as_str(), c.as_str()]);
+                    }
+                }
+            }
+        }
+        result.sort();
+        result
+    }
+
+    /// The largest set of nodes all connected to each other, sorted.
+    pub fn max_clique(&self) -> Vec<&str> {
+        let mut best = Vec::new();
+        let candidates: HashSet<&str> = self.edges.keys().map(|k| k.as_str()).collect();
+        self.bron_kerbosch(&mut Vec::new(), candidates, HashSet::new(), &mut best);
+        best.sort();
+        best
+    }
+
+    // Bron–Kerbosch with pivoting. `clique` is the clique being grown, `candidates` the nodes
+    // that extend it and `excluded` those already tried. Candidates next to the pivot are
+    // skipped, any maximal clique with them also holds the pivot or one of its non-neighbours.
+    fn bron_kerbosch<'a>(
+        &'a self,
+        clique: &mut Vec<&'a str>,
+        mut candidates: HashSet<&'a str>,
+        mut excluded: HashSet<&'a str>,
+        best: &mut Vec<&'a str>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() && clique.len() > best.len() {
+                *best = clique.clone();
+            }
+            return;
+        }
+
+        let pivot = candidates
+            .union(&excluded)
+            .max_by_key(|n| self.edges[**n].len())
+            .copied()
+            .unwrap();
+        let pivot_neighbors = &self.edges[pivot];
+        let to_try: Vec<&str> = candidates
+            .iter()
+            .filter(|n| !pivot_neighbors.contains(**n))
+            .copied()
+            .collect();
+
+        for node in to_try {
+            let neighbors = &self.edges[node];
+            clique.push(node);
+            self.bron_kerbosch(
+                clique,
+                candidates.iter().filter(|n| neighbors.contains(**n)).copied().collect(),
+                excluded.iter().filter(|n| neighbors.contains(**n)).copied().collect(),
+                best,
+            );
+            clique.pop();
+
+            candidates.remove(node);
+            excluded.insert(node);
+        }
+    }
+}
+
+/// Read the `aa-bb` connections.
+pub fn read_graph(input: &str) -> Graph {
+    let mut graph = Graph::new();
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        let (a, b) = line.split_once('-').expect("Expected aa-bb");
+        graph.add_edge(a, b);
+    }
+    graph
+}
+
+/// Groups of three connected computers where one name starts with `t`.
+pub fn part_1(input: &str) -> usize {
+    read_graph(input)
+        .triangles()
+        .iter()
+        .filter(|t| t.iter().any(|n| n.starts_with('t')))
+        .count()
+}
+
+/// Password to the LAN party, the names in the largest group joined with commas.
+pub fn part_2(input: &str) -> String {
+    read_graph(input).max_clique().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn given_example_expect_triangles() {
+        let graph = read_graph(EXAMPLE);
+
+        assert_eq!(graph.triangles().len(), 12);
+        assert_eq!(graph.triangles()[0], ["aq", "cg", "yn"]);
+        assert_eq!(part_1(EXAMPLE), 7);
+    }
+
+    #[test]
+    fn given_example_expect_password() {
+        assert_eq!(part_2(EXAMPLE), "co,de,ka,ta");
+    }
+
+    #[test]
+    fn given_complete_graph_expect_every_node_in_clique() {
+        let mut graph = Graph::new();
+        let nodes = ["e", "d", "c", "b", "a"];
+        for (i, a) in nodes.iter().enumerate() {
+            for b in nodes[i + 1..].iter() {
+                graph.add_edge(a, b);
+            }
+        }
+        graph.add_edge("a", "z");
+
+        assert_eq!(graph.max_clique(), vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(graph.triangles().len(), 10);
+        assert!(graph.neighbors("missing").is_empty());
+    }
+}
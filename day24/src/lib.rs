@@ -0,0 +1,358 @@
+//! Day 24: Crossed Wires. Simulate a circuit of logic gates and find the swapped wires that stop
+//! it from adding.
+
+use std::collections::{BTreeSet, HashMap};
+
+/// Operation of a gate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    pub fn from_name(name: &str) -> Option<Op> {
+        match name {
+            "AND" => Some(Op::And),
+            "OR" => Some(Op::Or),
+            "XOR" => Some(Op::Xor),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Op::And => "AND",
+            Op::Or => "OR",
+            Op::Xor => "XOR",
+        }
+    }
+
+    pub fn apply(&self, a: bool, b: bool) -> bool {
+        match self {
+            Op::And => a && b,
+            Op::Or => a || b,
+            Op::Xor => a ^ b,
+        }
+    }
+}
+
+/// A gate reading two wires and writing its output wire.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gate {
+    pub a: String,
+    pub op: Op,
+    pub b: String,
+    pub out: String,
+}
+
+impl Gate {
+    fn has_input(&self, wire: &str) -> bool {
+        self.a == wire || self.b == wire
+    }
+
+    // Reads an x and a y wire directly
+    fn reads_inputs(&self) -> bool {
+        let input = |w: &str| bit(w, 'x').is_some() || bit(w, 'y').is_some();
+        input(&self.a) && input(&self.b)
+    }
+
+    // Reads the lowest input bit
+    fn reads_first_bit(&self) -> bool {
+        self.has_input("x00") && self.has_input("y00")
+    }
+}
+
+/// The initial wire values and the gates between the wires.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    pub initial: HashMap<String, bool>,
+    pub gates: Vec<Gate>,
+}
+
+fn wire(prefix: char, bit: usize) -> String {
+    format!("{}{:02}", prefix, bit)
+}
+
+// The bit of a wire named `prefix` and digits, like `z07`, `None` for any other wire
+fn bit(wire: &str, prefix: char) -> Option<u32> {
+    let digits = wire.strip_prefix(prefix)?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+impl Circuit {
+    /// Set the `x` or `y` wires to the bits of a number.
+    pub fn set_number(&mut self, prefix: char, bits: usize, value: u64) {
+        for bit in 0..bits {
+            self.initial.insert(wire(prefix, bit), value >> bit & 1 == 1);
+        }
+    }
+
+    /// Value of every wire once the signals settle. Fails when gates wait on each other in a loop
+    /// or on wires nothing drives.
+    pub fn evaluate(&self) -> Result<HashMap<String, bool>, String> {
+        let mut values = self.initial.clone();
+        let mut pending: Vec<&Gate> = self.gates.iter().collect();
+
+        while !pending.is_empty() {
+            let before = pending.len();
+            pending.retain(|gate| match (values.get(&gate.a), values.get(&gate.b)) {
+                (Some(a), Some(b)) => {
+                    values.insert(gate.out.clone(), gate.op.apply(*a, *b));
+                    false
+                }
+                _ => true,
+            });
+
+            if pending.len() == before {
+                return Err(format!("{} gates never receive both inputs", pending.len()));
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// The number on the wires starting with `prefix`, `z00` being the lowest bit. Fails when a
+    /// set wire is past the 64 bits of the number.
+    pub fn number(values: &HashMap<String, bool>, prefix: char) -> Result<u64, String> {
+        values
+            .iter()
+            .filter(|(_, v)| **v)
+            .filter_map(|(w, _)| bit(w, prefix).map(|bit| (w, bit)))
+            .try_fold(0u64, |acc, (w, bit)| {
+                let value = 1u64.checked_shl(bit).ok_or(format!("Wire {} does not fit in 64 bits", w))?;
+                Ok(acc | value)
+            })
+    }
+
+    /// The number the circuit outputs on its `z` wires.
+    pub fn output(&self) -> Result<u64, String> {
+        Circuit::number(&self.evaluate()?, 'z')
+    }
+
+    /// Output wires that break the shape of a ripple-carry adder, sorted.
+    // Each bit of an adder is `s = x ^ y`, `z = s ^ carry_in`, `carry_out = (x & y) | (s & carry_in)`
+    // and the last carry is the top z. Only the first bit is a half adder. A wire is out of place when:
+    // - it is a z other than the top one, not made by an XOR
+    // - the top z is not made by an OR, or by the first bit's AND when that is the only bit
+    // - an XOR reading neither x nor y does not drive a z
+    // - an `x ^ y` past the first bit does not feed an XOR
+    // - an `x & y` past the first bit does not feed an OR
+    pub fn miswired(&self) -> Vec<String> {
+        let top = self.gates.iter().filter_map(|g| bit(&g.out, 'z')).max();
+        let feeds = |wire: &str, op: Op| self.gates.iter().any(|g| g.op == op && g.has_input(wire));
+
+        let mut wrong = BTreeSet::new();
+        for gate in self.gates.iter() {
+            let out = gate.out.as_str();
+            let z = bit(out, 'z');
+            let is_z = z.is_some();
+
+            let misplaced = if is_z && z == top {
+                gate.op != Op::Or && !(gate.op == Op::And && gate.reads_first_bit())
+            } else if is_z {
+                gate.op != Op::Xor
+            } else {
+                match gate.op {
+                    Op::Xor if !gate.reads_inputs() => true,
+                    Op::Xor => !gate.reads_first_bit() && !feeds(out, Op::Xor),
+                    Op::And => gate.reads_inputs() && !gate.reads_first_bit() && !feeds(out, Op::Or),
+                    Op::Or => false,
+                }
+            };
+
+            if misplaced {
+                wrong.insert(gate.out.clone());
+            }
+        }
+
+        wrong.into_iter().collect()
+    }
+
+    /// Swap the output wires of two gates.
+    pub fn swap_outputs(&mut self, a: &str, b: &str) {
+        for gate in self.gates.iter_mut() {
+            if gate.out == a {
+                gate.out = b.to_string();
+            } else if gate.out == b {
+                gate.out = a.to_string();
+            }
+        }
+    }
+
+    /// The circuit in Graphviz DOT format, a box per gate and an arrow per wire.
+    pub fn to_dot(&self) -> String {
+        let mut lines = vec![String::from("digraph circuit {")];
+        for (i, gate) in self.gates.iter().enumerate() {
+            lines.push(format!("    g{} [label=\"{}\" shape=box];", i, gate.op.name()));
+            lines.push(format!("    {} -> g{};", gate.a, i));
+            lines.push(format!("    {} -> g{};", gate.b, i));
+            lines.push(format!("    g{} -> {};", i, gate.out));
+        }
+        lines.push(String::from("}"));
+        lines.join("\n")
+    }
+}
+
+/// A correctly wired adder of two `bits` wide numbers, with `x` and `y` set to zero.
+pub fn ripple_carry_adder(bits: usize) -> Circuit {
+    let mut circuit = Circuit { initial: HashMap::new(), gates: Vec::new() };
+    circuit.set_number('x', bits, 0);
+    circuit.set_number('y', bits, 0);
+
+    let mut gate = |a: String, op: Op, b: String, out: String| {
+        circuit.gates.push(Gate { a, op, b, out });
+    };
+
+    gate(wire('x', 0), Op::Xor, wire('y', 0), wire('z', 0));
+    let first_carry = if bits == 1 { wire('z', 1) } else { wire('c', 0) };
+    gate(wire('x', 0), Op::And, wire('y', 0), first_carry);
+    for bit in 1..bits {
+        let carry_out = if bit == bits - 1 { wire('z', bits) } else { wire('c', bit) };
+        gate(wire('x', bit), Op::Xor, wire('y', bit), wire('s', bit));
+        gate(wire('x', bit), Op::And, wire('y', bit), wire('a', bit));
+        gate(wire('s', bit), Op::Xor, wire('c', bit - 1), wire('z', bit));
+        gate(wire('s', bit), Op::And, wire('c', bit - 1), wire('b', bit));
+        gate(wire('a', bit), Op::Or, wire('b', bit), carry_out);
+    }
+
+    circuit
+}
+
+/// Read the initial values and the gates after the blank line.
+pub fn read_circuit(input: &str) -> Circuit {
+    let (values, gates) = input.split_once("\n\n").unwrap_or((input, ""));
+
+    let initial = values
+        .lines()
+        .filter_map(|l| l.split_once(": "))
+        .map(|(w, v)| (w.trim().to_string(), v.trim() == "1"))
+        .collect();
+
+    let gates = gates
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let parts: Vec<&str> = l.split_whitespace().collect();
+            let [a, op, b, "->", out] = parts[..] else {
+                panic!("Invalid gate {}", l);
+            };
+            let op = Op::from_name(op).expect("Unknown gate");
+            Gate { a: a.to_string(), op, b: b.to_string(), out: out.to_string() }
+        })
+        .collect();
+
+    Circuit { initial, gates }
+}
+
+/// Number output on the z wires.
+pub fn part_1(input: &str) -> u64 {
+    read_circuit(input).output().expect("Circuit does not settle")
+}
+
+/// The swapped output wires, sorted and joined with commas.
+pub fn part_2(input: &str) -> String {
+    read_circuit(input).miswired().join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02";
+
+    fn add(circuit: &mut Circuit, bits: usize, x: u64, y: u64) -> Result<u64, String> {
+        circuit.set_number('x', bits, x);
+        circuit.set_number('y', bits, y);
+        circuit.output()
+    }
+
+    #[test]
+    fn given_small_example_expect_output() {
+        assert_eq!(part_1(SMALL), 4);
+    }
+
+    #[test]
+    fn given_synthetic_adder_expect_sums() {
+        let mut adder = ripple_carry_adder(8);
+
+        for (x, y) in [(0, 0), (1, 1), (200, 100), (255, 255), (37, 218)] {
+            assert_eq!(add(&mut adder, 8, x, y), Ok(x + y));
+        }
+        assert!(adder.miswired().is_empty());
+    }
+
+    #[test]
+    fn given_internal_wires_named_like_outputs_expect_adder_intact() {
+        let mut adder = ripple_carry_adder(4);
+        for gate in adder.gates.iter_mut() {
+            for w in [&mut gate.a, &mut gate.b, &mut gate.out] {
+                match w.as_str() {
+                    "s02" => *w = String::from("zkq"),
+                    "a03" => *w = String::from("xab"),
+                    _ => (),
+                }
+            }
+        }
+
+        assert_eq!(add(&mut adder, 4, 9, 7), Ok(16));
+        assert!(adder.miswired().is_empty(), "{:?}", adder.miswired());
+    }
+
+    #[test]
+    fn given_one_bit_adder_expect_carry_on_top_z() {
+        let mut adder = ripple_carry_adder(1);
+
+        assert_eq!(add(&mut adder, 1, 1, 1), Ok(2));
+        assert!(adder.miswired().is_empty());
+    }
+
+    #[test]
+    fn given_wire_past_64_bits_expect_error() {
+        let values = HashMap::from([(String::from("z63"), true), (String::from("z64"), true)]);
+
+        assert!(Circuit::number(&values, 'z').is_err());
+        assert_eq!(Circuit::number(&HashMap::from([(String::from("z63"), true)]), 'z'), Ok(1 << 63));
+    }
+
+    #[test]
+    fn given_swapped_outputs_expect_them_found() {
+        let mut adder = ripple_carry_adder(12);
+        adder.swap_outputs("z03", "c03");
+        adder.swap_outputs("s07", "a07");
+
+        assert_ne!(add(&mut adder, 12, 2047, 1), Ok(2048));
+        assert_eq!(adder.miswired(), vec!["a07", "c03", "s07", "z03"]);
+
+        adder.swap_outputs("z03", "c03");
+        adder.swap_outputs("s07", "a07");
+        assert!(adder.miswired().is_empty());
+    }
+
+    #[test]
+    fn given_swap_into_loop_expect_error() {
+        let mut adder = ripple_carry_adder(4);
+        adder.swap_outputs("s02", "z03");
+
+        assert!(adder.output().is_err());
+    }
+
+    #[test]
+    fn snapshot_two_bit_adder_dot() {
+        snapshot::assert_snapshot!("two_bit_adder", ripple_carry_adder(2).to_dot());
+    }
+}
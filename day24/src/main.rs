@@ -0,0 +1,11 @@
+fn main() {
+    let input = std::fs::read_to_string("input.txt").expect("Error reading input.txt");
+
+    if std::env::args().any(|a| a == "--dot") {
+        println!("{}", day24::read_circuit(&input).to_dot());
+        return;
+    }
+
+    println!("Part 1: {}", day24::part_1(&input));
+    println!("Part 2: {}", day24::part_2(&input));
+}
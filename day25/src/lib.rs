@@ -0,0 +1,169 @@
+//! Day 25: Code Chronicle. Count the lock and key pairs that fit together.
+
+use day4::{init_puzzle, CrosswordPuzzle, Puzzle};
+
+/// Height of the pins of a lock or the cuts of a key in each column.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Schematic {
+    Lock(Vec<usize>),
+    Key(Vec<usize>),
+}
+
+impl Schematic {
+    /// Read a schematic. Locks have their top row filled, keys their bottom row. Fails when
+    /// neither is filled.
+    pub fn new(grid: &Puzzle) -> Result<Schematic, String> {
+        // The filled top or bottom row is not part of the height
+        let heights = grid.column_profile('#').iter().map(|h| h.saturating_sub(1)).collect();
+        let filled = |row: usize| (0..grid.columns).all(|c| grid.char_at(row, c) == Some(&'#'));
+
+        if filled(0) {
+            Ok(Schematic::Lock(heights))
+        } else if filled(grid.rows.saturating_sub(1)) {
+            Ok(Schematic::Key(heights))
+        } else {
+            Err(String::from("Schematic has neither a filled top row nor a filled bottom row"))
+        }
+    }
+}
+
+/// The locks and keys, and the space between the top and bottom rows.
+#[derive(Debug)]
+pub struct Schematics {
+    pub locks: Vec<Vec<usize>>,
+    pub keys: Vec<Vec<usize>>,
+    pub space: usize,
+}
+
+impl Schematics {
+    /// Check if a key fits a lock without the pins and the key overlapping in any column.
+    pub fn fits(&self, lock: &[usize], key: &[usize]) -> bool {
+        lock.iter().zip(key.iter()).all(|(l, k)| l + k <= self.space)
+    }
+
+    /// Number of lock and key pairs that fit.
+    pub fn count_fits(&self) -> usize {
+        self.locks
+            .iter()
+            .map(|lock| self.keys.iter().filter(|key| self.fits(lock, key)).count())
+            .sum()
+    }
+}
+
+/// Read the schematics separated by blank lines. Fails on a block without both a top and a bottom
+/// row, on blocks of different heights and on a block that is neither a lock nor a key.
+pub fn read_schematics(input: &str) -> Result<Schematics, String> {
+    let mut schematics = Schematics { locks: Vec::new(), keys: Vec::new(), space: 0 };
+    let mut space = None;
+
+    for block in input.split("\n\n").filter(|b| !b.trim().is_empty()) {
+        let grid = init_puzzle(block.trim());
+        let rows = grid
+            .rows
+            .checked_sub(2)
+            .ok_or(format!("Schematic needs a top and a bottom row: {:?}", block.trim()))?;
+        let expected = *space.get_or_insert(rows);
+        if rows != expected {
+            return Err(format!("Schematic is {} rows high instead of {}: {:?}", grid.rows, expected + 2, block.trim()));
+        }
+
+        match Schematic::new(&grid).map_err(|e| format!("{}: {:?}", e, block.trim()))? {
+            Schematic::Lock(heights) => schematics.locks.push(heights),
+            Schematic::Key(heights) => schematics.keys.push(heights),
+        }
+    }
+
+    schematics.space = space.unwrap_or(0);
+    Ok(schematics)
+}
+
+/// Number of lock and key pairs that fit together.
+pub fn part_1(input: &str) -> usize {
+    read_schematics(input).expect("Invalid schematics").count_fits()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####";
+
+    #[test]
+    fn given_example_expect_heights() {
+        let schematics = read_schematics(EXAMPLE).unwrap();
+
+        assert_eq!(schematics.locks, vec![vec![0, 5, 3, 4, 3], vec![1, 2, 0, 5, 3]]);
+        assert_eq!(
+            schematics.keys,
+            vec![vec![5, 0, 2, 1, 3], vec![4, 3, 4, 0, 2], vec![3, 0, 2, 0, 1]]
+        );
+        assert_eq!(schematics.space, 5);
+    }
+
+    #[test]
+    fn given_example_expect_fitting_pairs() {
+        let schematics = read_schematics(EXAMPLE).unwrap();
+
+        assert!(!schematics.fits(&[0, 5, 3, 4, 3], &[5, 0, 2, 1, 3]));
+        assert!(schematics.fits(&[0, 5, 3, 4, 3], &[3, 0, 2, 0, 1]));
+        assert_eq!(part_1(EXAMPLE), 3);
+    }
+
+    #[test]
+    fn given_block_shorter_than_two_rows_expect_error() {
+        assert!(read_schematics("#####").is_err());
+        assert!(read_schematics(&format!("{}\n\n.....", EXAMPLE)).is_err());
+        assert_eq!(read_schematics("").unwrap().space, 0);
+    }
+
+    #[test]
+    fn given_blocks_of_different_heights_expect_error() {
+        assert!(read_schematics("#####\n.....\n.....\n\n.....\n#####").is_err());
+        assert_eq!(read_schematics("#####\n.....\n.....\n\n.....\n.#...\n#####").unwrap().space, 1);
+    }
+
+    #[test]
+    fn given_block_neither_lock_nor_key_expect_error() {
+        assert!(read_schematics("#.###\n.#...\n####.").is_err());
+        assert_eq!(read_schematics(".....\n.#...\n#####").unwrap().keys, vec![vec![0, 1, 0, 0, 0]]);
+    }
+}
@@ -0,0 +1,106 @@
+//! Day 3: Mull It Over. Scan corrupted memory for `mul(a,b)` instructions.
+
+/// Sum the products of every valid `mul(a,b)` instruction.
+pub fn find_products(buffer: &str) -> i32 {
+    let candidates: Vec<usize> = buffer.match_indices("mul(").map(|d| d.0).collect();
+
+    let result: Vec<Option<i32>> = candidates
+        .iter()
+        .map(|e| parse_mul(&buffer[e+4..]))
+        .collect();
+
+    result
+        .iter()
+        .map(|r| r.unwrap_or(0))
+        .sum()
+}
+
+/// Sum the products of the `mul(a,b)` instructions enabled by `do()` and `don't()`.
+pub fn find_products_with_conditionals(buffer: &str) -> i32 {
+    let chars = buffer.chars();
+    let mut processing = true;
+    let mut work_buf: String = String::new();
+    let mut result: Vec<i32> = Vec::new();
+
+    for (i, c) in chars.enumerate() {
+        work_buf.push(c);
+
+        if work_buf.ends_with("do()") {
+            processing = true;
+            work_buf.clear();
+        } else if work_buf.ends_with("don't()") {
+            processing = false;
+            work_buf.clear();
+        } else if work_buf.ends_with("mul(") && processing {
+            work_buf.clear();
+            result.push(parse_mul(&buffer[i+1..]).unwrap_or(0));
+        }
+    }
+
+    result
+        .iter()
+        .sum()
+}
+
+/// Parse the `a,b)` following a `mul(` and return the product.
+pub fn parse_mul(buf: &str) -> Option<i32>{
+
+    let mut chars = buf.chars().peekable();
+    let mut work_buf = String::new();
+
+    while let Some(c) = chars.next_if(|n| n.is_ascii_digit()) {
+        work_buf.push(c);
+    }
+
+    let v1 = work_buf.parse::<i32>().ok()?;
+
+    if chars.peek() == Some(&',') {
+        chars.next();
+    } else {
+        return None
+    }
+
+    work_buf.clear();
+    while let Some(c) = chars.next_if(|n| n.is_ascii_digit()) {
+        work_buf.push(c);
+    }
+
+    let v2 = work_buf.parse::<i32>().ok()?;
+
+    if chars.peek() != Some(&')') {
+        return None;
+    }
+
+    Some(v1 * v2)
+}
+
+/// Sum of all products.
+pub fn part_1(input: &str) -> i32 {
+    find_products(input)
+}
+
+/// Sum of the enabled products.
+pub fn part_2(input: &str) -> i32 {
+    find_products_with_conditionals(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SAMPLE: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const SAMPLE2: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn given_sample_expect_161() {
+        let r = find_products(SAMPLE);
+        assert_eq!(r, 161);
+    }
+
+    #[test]
+    fn given_sample_with_conditionals_expect_48() {
+        let r = find_products_with_conditionals(SAMPLE2);
+        assert_eq!(r, 48);
+    }
+
+}
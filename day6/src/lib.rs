@@ -0,0 +1,310 @@
+//! Day 6: Guard Gallivant. Walk a guard around a lab until it leaves or loops.
+
+use std::collections::HashMap;
+
+/// The lab map, the guard position and the path it walked.
+#[derive(Debug)]
+pub struct PuzzleMap {
+    pub rows: usize,
+    pub cols: usize,
+    pub obstructions: HashMap<(usize, usize), bool>,
+    pub visited: HashMap<(usize, usize), usize>,
+    pub start: (usize, usize, Direction),
+    pub guard: (usize, usize, Direction),
+    pub history: Vec<(usize, usize, Direction)>
+}
+
+/// Facing of the guard on the map.
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    /// All directions, clockwise from up.
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Direction for one of the `^>v<` arrows.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None
+        }
+    }
+
+    /// The `^>v<` arrow of the direction.
+    pub fn to_char(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<'
+        }
+    }
+
+    /// Direction after turning right.
+    pub fn turn_right(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up
+        }
+    }
+
+    /// Direction after turning left.
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down
+        }
+    }
+
+    /// Row and column change of a single step.
+    pub fn delta(&self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1)
+        }
+    }
+
+    /// Position one step away, `None` when it would go below row or column 0.
+    pub fn step(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        let (dr, dc) = self.delta();
+        Some((row.checked_add_signed(dr)?, col.checked_add_signed(dc)?))
+    }
+}
+
+impl PuzzleMap {
+    /// Put the guard back at the start and forget the walked path.
+    pub fn reset(&mut self) {
+        self.guard = self.start;
+        self.history.clear();
+        self.visited.clear();
+    }
+
+    /// Walk the guard until it leaves the map. Returns `Err` when the guard is stuck in a loop.
+    #[allow(clippy::result_unit_err)]
+    pub fn part_1(&mut self) -> Result<(), ()> {
+       loop {
+           let next_opt = match self.guard.2 {
+               Direction::Up => (self.guard.0.checked_sub(1), Some(self.guard.1)),
+               Direction::Right => (Some(self.guard.0), self.guard.1.checked_add( 1)),
+               Direction::Down => (self.guard.0.checked_add(1), Some(self.guard.1)),
+               Direction::Left => (Some(self.guard.0), self.guard.1.checked_sub(1)),
+           };
+
+           if next_opt.0.is_none() || next_opt.1.is_none() {
+               return Ok(());
+           }
+
+           let next = (next_opt.0.unwrap(), next_opt.1.unwrap());
+           if !(0..self.rows).contains(&next.0) || !(0..self.cols).contains(&next.1) {
+               return Ok(());
+           }
+
+           if self.history.contains(&(next.0, next.1, self.guard.2)) {
+               return Err(())
+           }
+
+           let obs = self.obstructions.get(&next);
+           match obs {
+               Some(_obs) => self.guard.2 = self.next_dir(),
+               None => {
+                   self.guard.0 = next.0;
+                   self.guard.1 = next.1;
+                   *self.visited.entry(next).or_insert(0) += 1;
+                   self.history.push(self.guard);
+               }
+           }
+       }
+    }
+
+    /// Count the visited positions where a new obstruction puts the guard in a loop.
+    pub fn part_2(&mut self) -> usize {
+        let keys: Vec<(usize, usize)> = self.visited.keys().map(|k| (k.0, k.1)).collect();
+        let mut total: usize = 0;
+
+        for k in keys {
+            self.reset();
+
+            self.obstructions.insert(k, true);
+            let r = self.part_1();
+            total += match r {
+                Ok(()) => 0,
+                Err(()) => 1
+            };
+            self.obstructions.remove(&k);
+        }
+        total
+    }
+
+    /// Draw the walked path: '|' and '-' for vertical and horizontal moves, '+' where the guard
+    /// turned or crossed its own path, '#' for obstructions and the guard's facing at its start.
+    pub fn render(&self) -> String {
+        let mut grid = vec![vec!['.'; self.cols]; self.rows];
+
+        for (row, col) in self.obstructions.keys() {
+            grid[*row][*col] = '#';
+        }
+
+        for (i, (row, col, dir)) in self.history.iter().enumerate() {
+            let turned = matches!(self.history.get(i + 1), Some(next) if next.2 != *dir);
+            let mark = match dir {
+                _ if turned => '+',
+                Direction::Up | Direction::Down => '|',
+                Direction::Right | Direction::Left => '-',
+            };
+
+            let cell = &mut grid[*row][*col];
+            *cell = match *cell {
+                '.' => mark,
+                c if c == mark => mark,
+                _ => '+',
+            };
+        }
+
+        grid[self.start.0][self.start.1] = self.start.2.to_char();
+
+        grid.into_iter()
+            .map(|r| r.into_iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Direction after the guard turns right.
+    pub fn next_dir(&mut self) -> Direction {
+        self.guard.2.turn_right()
+    }
+}
+
+/// Build the map from its lines. `#` are obstructions and `^>v<` is the guard.
+pub fn init_map(input: Vec<&str>) -> PuzzleMap {
+    let mut obstructions: HashMap<(usize, usize), bool> = HashMap::new();
+    let mut visited: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut history: Vec<(usize, usize, Direction)> = Vec::with_capacity(10000);
+    let rows = input.len();
+    let cols = input[0].len();
+    let mut guard= (0, 0, Direction::Up);
+
+    input
+        .iter()
+        .enumerate()
+        .for_each(|(row, line)| {
+                line.
+                    chars()
+                    .enumerate()
+                    .for_each(
+                        |(col, chr)| {
+                            if let Some(dir) = Direction::from_char(chr) {
+                                guard = (row, col, dir);
+                            } else if chr == '#' {
+                                obstructions.insert((row, col), true);
+                            }
+                        }
+                    );
+            }
+        );
+
+    visited.insert((guard.0, guard.1), 1);
+    history.push(guard);
+
+    PuzzleMap {
+        rows,
+        cols,
+        obstructions,
+        visited,
+        start: guard,
+        guard,
+        history
+    }
+}
+
+/// Number of positions the guard visits.
+pub fn part_1(input: &str) -> usize {
+    let mut puzzle_map = init_map(input.lines().collect());
+
+    let _ = puzzle_map.part_1();
+    puzzle_map.visited.len()
+}
+
+/// Number of positions where an obstruction makes the guard loop.
+pub fn part_2(input: &str) -> usize {
+    let mut puzzle_map = init_map(input.lines().collect());
+
+    let _ = puzzle_map.part_1();
+    puzzle_map.part_2()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_INPUT: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn given_sample_part_1_expect_41(){
+        let mut puzzle_map = init_map(TEST_INPUT.lines().collect());
+
+        // println!("{:?}", puzzle_map.);
+        let _ = puzzle_map.part_1();
+        assert_eq!(puzzle_map.visited.len(), 41);
+    }
+
+    #[test]
+    fn snapshot_sample_path() {
+        let mut puzzle_map = init_map(TEST_INPUT.lines().collect());
+
+        let _ = puzzle_map.part_1();
+        snapshot::assert_snapshot!("sample_path", puzzle_map.render());
+    }
+
+    #[test]
+    fn snapshot_sample_loop() {
+        let mut puzzle_map = init_map(TEST_INPUT.lines().collect());
+        puzzle_map.obstructions.insert((6, 3), true);
+
+        assert_eq!(puzzle_map.part_1(), Err(()));
+        snapshot::assert_snapshot!("sample_loop", puzzle_map.render());
+    }
+
+    #[test]
+    fn given_direction_expect_turns_and_steps() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Down.step(3, 4), Some((4, 4)));
+        assert_eq!(Direction::Left.step(3, 0), None);
+        assert!(Direction::ALL.iter().all(|d| Direction::from_char(d.to_char()) == Some(*d)));
+    }
+
+    #[test]
+    fn given_sample_part_2_expect_6(){
+        let mut puzzle_map = init_map(TEST_INPUT.lines().collect());
+
+        let _ = puzzle_map.part_1();
+        assert_eq!(puzzle_map.part_2(), 6);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+//! Day 7: Bridge Repair. Find which operators make the calibration equations true.
+
+/// Operators that can be placed between the operands, always evaluated left to right.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Operator {
+    /// Apply the operator to two operands.
+    pub fn apply(&self, l: usize, r: usize) -> usize {
+        match self {
+            Operator::Add => l + r,
+            Operator::Multiply => l * r,
+            Operator::Concat => format!("{}{}", l, r).parse::<usize>().unwrap(),
+        }
+    }
+}
+
+/// Operators available in part 1.
+pub const PART_1_OPERATORS: [Operator; 2] = [Operator::Add, Operator::Multiply];
+
+/// Operators available in part 2.
+pub const PART_2_OPERATORS: [Operator; 3] = [Operator::Add, Operator::Multiply, Operator::Concat];
+
+/// Return the test value of an equation if the operators can make it true, 0 otherwise.
+pub fn parse_line(input: &str, operators: &[Operator]) -> usize {
+    let pair = input.split_once(": ");
+    let (result, operands) = match pair {
+        Some((l, r)) => &mut (
+            l.parse::<usize>().unwrap(),
+            r.split(' ').map(|x| x.parse::<usize>().unwrap()).collect(),
+        ),
+        None => &mut (0, Vec::new()),
+    };
+
+    operands.reverse();
+    if resolve(*result, operands, operators) {
+        return *result;
+    }
+
+    0
+}
+
+/// Check if the operands, reversed so the first one is last, can be combined into `result`.
+pub fn resolve(result: usize, operands: &mut Vec<usize>, operators: &[Operator]) -> bool {
+
+    if operands.len() == 1 {
+        return result == operands[0];
+    }
+
+    let l = operands.pop().unwrap();
+    let r = operands.pop().unwrap();
+
+    operators.iter().any(|op| {
+        let mut next = operands.clone();
+        next.push(op.apply(l, r));
+        resolve(result, &mut next, operators)
+    })
+}
+
+/// Total calibration result using add and multiply.
+pub fn part_1(input: &str) -> usize {
+    input.lines().map(|l| parse_line(l, &PART_1_OPERATORS)).sum()
+}
+
+/// Total calibration result using add, multiply and concatenation.
+pub fn part_2(input: &str) -> usize {
+    input.lines().map(|l| parse_line(l, &PART_2_OPERATORS)).sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_INPUT: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn given_sample_expect_sum() {
+        let total = TEST_INPUT.lines().map(|l| parse_line(l, &PART_1_OPERATORS)).sum::<usize>();
+
+        assert_eq!(total, 3749);
+    }
+
+    #[test]
+    fn given_sample_with_concat_expect_sum() {
+        let total = TEST_INPUT.lines().map(|l| parse_line(l, &PART_2_OPERATORS)).sum::<usize>();
+
+        assert_eq!(total, 11387);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line("190: 10 19", &PART_1_OPERATORS), 190);
+        assert_eq!(parse_line("3267: 81 40 27", &PART_1_OPERATORS), 3267);
+        assert_eq!(parse_line("292: 11 6 16 20", &PART_1_OPERATORS), 292);
+        assert_eq!(parse_line("156: 15 6", &PART_1_OPERATORS), 0);
+        assert_eq!(parse_line("156: 15 6", &PART_2_OPERATORS), 156);
+    }
+}
@@ -0,0 +1,224 @@
+//! Day 8: Resonant Collinearity. Find the antinodes created by pairs of antennas.
+
+use std::collections::{HashMap, HashSet};
+
+/// A position on the map.
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// Antennas grouped by frequency and the antinodes found with resonant harmonics.
+#[derive(Debug)]
+pub struct Map {
+    pub rows: usize,
+    pub cols: usize,
+    pub antennas: HashMap<char, Vec<Point>>,
+    pub antinodes: HashSet<Point>,
+}
+
+impl Map {
+    /// Antinodes found without resonant harmonics, one on each side of every antenna pair.
+    pub fn adjacent_antinodes(&self) -> HashSet<Point> {
+        let mut anodes = HashSet::new();
+        for antennas in self.antennas.values() {
+            for (i, a) in antennas.iter().enumerate() {
+                for p in &antennas[i + 1..] {
+                    let n1 = calc_antinode(a, p, self.rows as isize, self.cols as isize);
+                    let n2 = calc_antinode(p, a, self.rows as isize, self.cols as isize);
+                    anodes.extend(n1);
+                    anodes.extend(n2);
+                }
+            }
+        }
+        anodes
+    }
+}
+
+/// Read the antennas of the map and find their antinodes with resonant harmonics.
+pub fn read_map(input: &str) -> Map {
+    let mut antennas: HashMap<char, Vec<Point>> = HashMap::new();
+    let mut antinodes = HashSet::new();
+    let rows = input.lines().count();
+    let cols = input.lines().next().unwrap().len();
+
+    input.lines().enumerate().for_each(|(row, line)| {
+        let chars = line.chars();
+        for (col, c) in chars.enumerate() {
+            if c != '.' {
+                let p = Point { row, col };
+                if let Some(existing) = antennas.get(&c) {
+                    let nodes: Vec<Point> = find_antinodes(existing, &p, rows, cols);
+                    nodes.into_iter().for_each(|p| {
+                        antinodes.insert(p);
+                    });
+                }
+
+                antennas
+                    .entry(c)
+                    .or_default()
+                    .push(Point { row, col });
+            }
+        }
+    });
+
+    Map {
+        rows,
+        cols,
+        antennas,
+        antinodes,
+    }
+}
+
+/// Antinodes with resonant harmonics between a new antenna and the antennas of its frequency.
+pub fn find_antinodes(antennas: &[Point], p: &Point, rows: usize, cols: usize) -> Vec<Point> {
+    let mut anodes = Vec::new();
+    for a in antennas {
+        let mut n1: Vec<Point> = calc_antinode_p2(a, p, rows as isize, cols as isize);
+        let mut n2: Vec<Point> = calc_antinode_p2(p, a, rows as isize, cols as isize);
+        anodes.append(&mut n1);
+        anodes.append(&mut n2);
+    }
+    anodes
+}
+
+/// The antinode beyond `p2`, as far from `p2` as `p2` is from `p1`.
+pub fn calc_antinode(p1: &Point, p2: &Point, max_rows: isize, max_cols: isize) -> Option<Point> {
+    let dx = p2.row as isize - p1.row as isize;
+    let dy = p2.col as isize - p1.col as isize;
+
+    let tmp = (p2.row as isize + dx, p2.col as isize + dy);
+
+    if (0..max_rows).contains(&tmp.0) && (0..max_cols).contains(&tmp.1) {
+        return Some(Point {
+            row: tmp.0 as usize,
+            col: tmp.1 as usize,
+        });
+    }
+
+    None
+}
+
+/// Every antinode from `p2` onwards in the direction of `p1` to `p2`, including `p2` itself.
+pub fn calc_antinode_p2(p1: &Point, p2: &Point, max_rows: isize, max_cols: isize) -> Vec<Point> {
+    let mut r = Vec::new();
+    let dx = p2.row as isize - p1.row as isize;
+    let dy = p2.col as isize - p1.col as isize;
+
+    // Include the directional antenna 😔
+    r.push(Point {
+        row: p2.row,
+        col: p2.col,
+    });
+
+    let mut tmp = (p2.row as isize + dx, p2.col as isize + dy);
+    while (0..max_rows).contains(&tmp.0) && (0..max_cols).contains(&tmp.1) {
+        r.push(Point {
+            row: tmp.0 as usize,
+            col: tmp.1 as usize,
+        });
+        tmp = (tmp.0 + dx, tmp.1 + dy);
+    }
+
+    r
+}
+
+/// Number of antinode locations without harmonics.
+pub fn part_1(input: &str) -> usize {
+    read_map(input).adjacent_antinodes().len()
+}
+
+/// Number of antinode locations with resonant harmonics.
+pub fn part_2(input: &str) -> usize {
+    read_map(input).antinodes.len()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const TEST_INPUT: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn test_init() {
+        let m = read_map(TEST_INPUT);
+
+        assert_eq!(m.rows, 12);
+        assert_eq!(m.cols, 12);
+        assert_eq!(m.antennas.len(), 2);
+        assert_eq!(m.adjacent_antinodes().len(), 14);
+        assert_eq!(m.antinodes.len(), 34);
+    }
+
+    #[test]
+    fn test_calc_antinodes() {
+        assert_eq!(
+            calc_antinode(&Point { row: 2, col: 2 }, &Point { row: 4, col: 3 }, 10, 10),
+            Some(Point { row: 6, col: 4 })
+        );
+
+        assert_eq!(
+            calc_antinode(&Point { row: 4, col: 3 }, &Point { row: 2, col: 2 }, 10, 10),
+            Some(Point { row: 0, col: 1 })
+        );
+
+        assert_eq!(
+            calc_antinode(&Point { row: 4, col: 3 }, &Point { row: 2, col: 2 }, 10, 10),
+            Some(Point { row: 0, col: 1 })
+        );
+
+        assert_eq!(
+            calc_antinode(&Point { row: 3, col: 3 }, &Point { row: 6, col: 6 }, 10, 10),
+            Some(Point { row: 9, col: 9 })
+        );
+
+        assert_eq!(
+            calc_antinode(&Point { row: 3, col: 7 }, &Point { row: 6, col: 3 }, 10, 10),
+            None
+        );
+
+        assert_eq!(
+            calc_antinode(&Point { row: 8, col: 8 }, &Point { row: 2, col: 2 }, 10, 10),
+            None
+        );
+
+        assert_eq!(
+            calc_antinode(&Point { row: 2, col: 2 }, &Point { row: 8, col: 8 }, 10, 10),
+            None
+        );
+    }
+
+    #[test]
+    fn test_calc_antinodes_2() {
+        assert_eq!(
+            calc_antinode_p2(&Point { row: 3, col: 3 }, &Point { row: 2, col: 2 }, 6, 6),
+            vec![
+                Point { row: 2, col: 2 },
+                Point { row: 1, col: 1 },
+                Point { row: 0, col: 0 },
+            ]
+        );
+
+        assert_eq!(
+            calc_antinode_p2(&Point { row: 2, col: 2 }, &Point { row: 3, col: 3 }, 6, 6),
+            vec![
+                Point { row: 3, col: 3 },
+                Point { row: 4, col: 4 },
+                Point { row: 5, col: 5 },
+            ]
+        );
+    }
+}
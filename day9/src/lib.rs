@@ -0,0 +1,216 @@
+//! Day 9: Disk Fragmenter. Compact the files of a disk map and compute its checksum.
+
+use std::cmp::min;
+use std::collections::VecDeque;
+
+/// A file and the block ranges it occupies.
+#[derive(Debug)]
+pub struct FileNode {
+    pub id: u64,
+    pub parts: VecDeque<(u32, u32)>, // start, length
+}
+
+/// The files on the disk and the free block ranges between them.
+#[derive(Debug)]
+pub struct DiskMap {
+    pub files: Vec<FileNode>,
+    pub free_pages: VecDeque<(u32, u32)>, // start, length
+}
+
+/// Move file blocks one at a time from the end of the disk into the leftmost free space.
+// Iterate over all files in reverse, if file starts after the earliest free page, then we process.
+// Assumption: files and free pages are sorted by start index
+// Assumption: input is clean with no fragmentation existing
+pub fn fragment(dm: &mut DiskMap) {
+    for f in &mut dm.files.iter_mut().rev() {
+        let mut part = f.parts.pop_front().unwrap();
+        while part.1 > 0 {
+            let mut free_page = dm.free_pages.pop_front().unwrap();
+            if free_page.1 == 0 {
+                continue;
+            }
+
+            if free_page.0 >= part.0 {
+                f.parts.push_back(part);
+                dm.free_pages.push_front(free_page);
+                break;
+            }
+
+            let move_length = min(part.1, free_page.1);
+            let file_part: (u32, u32) = (free_page.0, move_length);
+            f.parts.push_back(file_part);
+
+            // Keep track of the amount moved
+            part.1 -= move_length;
+            free_page.0 += move_length;
+            free_page.1 -= move_length;
+
+            // Add moved part back to free_pages
+
+            dm.free_pages.push_front(free_page);
+        }
+    }
+}
+
+/// Move whole files, highest id first, into the leftmost free space that fits them.
+pub fn defragment_whole_files(dm: &mut DiskMap) {
+    dm.files.sort_by_key(|f| f.id);
+    for f in &mut dm.files.iter_mut().rev() {
+        let part: &mut (u32, u32) = f.parts.get_mut(0).unwrap();
+        if part.1 == 0 {
+            continue;
+        }
+
+        for free_page in &mut dm.free_pages.iter_mut() {
+            if free_page.1 >= part.1 && free_page.0 < part.0 {
+                part.0 = free_page.0;
+                free_page.0 += part.1;
+                free_page.1 -= part.1;
+
+                break;
+            }
+        }
+    }
+}
+
+/// Sum of every block position multiplied by the id of the file in it.
+pub fn checksum(disk: &DiskMap) -> u64 {
+    disk.files.iter().map(checksum_file).sum()
+}
+
+/// Checksum of the blocks of a single file.
+pub fn checksum_file(f: &FileNode) -> u64 {
+    f.parts
+        .iter()
+        .map(|p| (p.0..p.0 + p.1).fold(0, |acc, n| acc + (f.id * n as u64)))
+        .sum()
+}
+
+/// Render the disk one character per block: the file id for file blocks and '.' for free space.
+/// Ids above 9 continue with letters so the layout stays one character per block.
+/// Free space after the last file block is not tracked once files move, so it is left off.
+pub fn render(disk: &DiskMap) -> String {
+    let file_end = disk.files.iter().flat_map(|f| f.parts.iter()).map(|p| p.0 + p.1);
+    let free_end = disk.free_pages.iter().map(|p| p.0 + p.1);
+    let length = file_end.chain(free_end).max().unwrap_or(0);
+
+    let mut blocks = vec!['.'; length as usize];
+    for f in &disk.files {
+        let c = char::from_digit(f.id as u32, 36).unwrap_or('#');
+        for p in &f.parts {
+            for i in p.0..p.0 + p.1 {
+                blocks[i as usize] = c;
+            }
+        }
+    }
+
+    blocks.into_iter().collect::<String>().trim_end_matches('.').to_string()
+}
+
+/// Read the dense disk map of alternating file and free space lengths.
+pub fn init_map(input: &str) -> DiskMap {
+    let mut files: Vec<FileNode> = Vec::new();
+    let mut free_pages: VecDeque<(u32, u32)> = VecDeque::new();
+    let mut is_file = true;
+    let mut offset = 0;
+    let mut id = 0;
+
+    for c in input.trim().chars() {
+        let val: u32 = c.to_digit(10).unwrap();
+        if is_file {
+            files.push(FileNode {
+                id,
+                parts: VecDeque::from(vec![(offset, val)]),
+            });
+            id += 1;
+        } else if val > 0 {
+            free_pages.push_back((offset, val));
+        }
+
+        offset += val;
+        is_file = !is_file;
+    }
+
+    DiskMap { files, free_pages }
+}
+
+/// Checksum after moving single blocks.
+pub fn part_1(input: &str) -> u64 {
+    let mut map = init_map(input);
+    fragment(&mut map);
+    checksum(&map)
+}
+
+/// Checksum after moving whole files.
+pub fn part_2(input: &str) -> u64 {
+    let mut map = init_map(input);
+    defragment_whole_files(&mut map);
+    checksum(&map)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "2333133121414131402";
+
+    #[test]
+    fn test_part_1() {
+        let mut dm = init_map(INPUT);
+        fragment(&mut dm);
+        assert_eq!(checksum(&dm), 1928);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let mut dm = init_map(INPUT);
+        defragment_whole_files(&mut dm);
+        assert_eq!(checksum(&dm), 2858);
+    }
+
+    #[test]
+    fn snapshot_initial_layout() {
+        let dm = init_map(INPUT);
+        snapshot::assert_snapshot!("initial_layout", render(&dm));
+    }
+
+    #[test]
+    fn snapshot_fragmented_layout() {
+        let mut dm = init_map(INPUT);
+        fragment(&mut dm);
+        snapshot::assert_snapshot!("fragmented_layout", render(&dm));
+    }
+
+    #[test]
+    fn snapshot_defragmented_layout() {
+        let mut dm = init_map(INPUT);
+        defragment_whole_files(&mut dm);
+        snapshot::assert_snapshot!("defragmented_layout", render(&dm));
+    }
+
+    #[test]
+    fn validate_checksum() {
+        let dm = DiskMap {
+            files: vec![
+                FileNode {
+                    id: 0,
+                    parts: vec![(0, 1)].into(),
+                },
+                FileNode {
+                    id: 9,
+                    parts: vec![(2, 2)].into(),
+                },
+                FileNode {
+                    id: 8,
+                    parts: vec![(4, 1), (8, 3)].into(),
+                },
+            ],
+            free_pages: vec![].into(),
+        };
+
+        assert_eq!(checksum_file(&dm.files[0]), 0);
+        assert_eq!(checksum_file(&dm.files[1]), 45);
+        assert_eq!(checksum_file(&dm.files[2]), 248);
+        assert_eq!(checksum(&dm), 293);
+    }
+}
@@ -0,0 +1,140 @@
+//! Golden snapshot testing for rendered puzzle output.
+//!
+//! A snapshot is a plain text `.snap` file checked in next to the crate that uses it. Tests
+//! compare rendered text against it with [`assert_snapshot!`]. When a rendering changes on
+//! purpose, run the tests with `UPDATE_SNAPSHOTS=1` to re-bless the files.
+
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable that makes [`assert_snapshot`] overwrite snapshots instead of comparing,
+/// when set to `1` or `true`.
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Compare `actual` against `snapshots/<name>.snap` inside the calling crate.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::assert_snapshot(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/snapshots"),
+            $name,
+            &$actual,
+        )
+    };
+}
+
+/// Compare `actual` against `<dir>/<name>.snap`, or write it when [`UPDATE_ENV`] is `1` or `true`.
+///
+/// Panics with a line diff if the snapshot is missing or does not match.
+pub fn assert_snapshot(dir: impl AsRef<Path>, name: &str, actual: &str) {
+    let path = snapshot_path(dir.as_ref(), name);
+    let actual = normalize(actual);
+
+    if updating(std::env::var_os(UPDATE_ENV).as_deref()) {
+        fs::create_dir_all(dir.as_ref()).expect("Unable to create snapshot directory");
+        fs::write(&path, &actual).expect("Unable to write snapshot");
+        return;
+    }
+
+    let Ok(expected) = fs::read_to_string(&path) else {
+        panic!(
+            "Missing snapshot {}, run with {}=1 to create it",
+            path.display(),
+            UPDATE_ENV
+        );
+    };
+
+    if let Err(diff) = compare(&normalize(&expected), &actual) {
+        panic!(
+            "Snapshot {} does not match, run with {}=1 to update it\n{}",
+            path.display(),
+            UPDATE_ENV,
+            diff
+        );
+    }
+}
+
+// Any other value, like `0`, leaves the snapshots alone
+fn updating(value: Option<&OsStr>) -> bool {
+    matches!(value.and_then(OsStr::to_str), Some("1" | "true"))
+}
+
+fn snapshot_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{}.snap", name))
+}
+
+// Snapshots always end with exactly one newline and never contain '\r'
+fn normalize(text: &str) -> String {
+    let mut result = text.replace("\r\n", "\n").trim_end_matches('\n').to_string();
+    result.push('\n');
+    result
+}
+
+/// Compare two texts line by line, returning a diff of the differing lines on mismatch.
+pub fn compare(expected: &str, actual: &str) -> Result<(), String> {
+    if expected == actual {
+        return Ok(());
+    }
+
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => diff.push_str(&format!("  {:>4} {}\n", i + 1, e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    diff.push_str(&format!("- {:>4} {}\n", i + 1, e));
+                }
+                if let Some(a) = a {
+                    diff.push_str(&format!("+ {:>4} {}\n", i + 1, a));
+                }
+            }
+        }
+    }
+
+    Err(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn given_equal_text_expect_match() {
+        assert!(compare("ab\ncd\n", "ab\ncd\n").is_ok());
+    }
+
+    #[test]
+    fn given_changed_line_expect_diff() {
+        let diff = compare("ab\ncd\n", "ab\nce\nfg\n").unwrap_err();
+        assert_eq!(diff, "     1 ab\n-    2 cd\n+    2 ce\n+    3 fg\n");
+    }
+
+    #[test]
+    fn given_trailing_newlines_expect_normalized() {
+        assert_eq!(normalize("ab\r\ncd\n\n\n"), "ab\ncd\n");
+        assert_eq!(normalize("ab"), "ab\n");
+    }
+
+    #[test]
+    fn given_update_values_expect_only_one_or_true_to_bless() {
+        assert!(updating(Some(OsStr::new("1"))));
+        assert!(updating(Some(OsStr::new("true"))));
+        assert!(!updating(Some(OsStr::new("0"))));
+        assert!(!updating(Some(OsStr::new("false"))));
+        assert!(!updating(Some(OsStr::new(""))));
+        assert!(!updating(None));
+    }
+
+    #[test]
+    fn given_existing_snapshot_expect_match() {
+        let dir = std::env::temp_dir().join("snapshot-self-test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(snapshot_path(&dir, "existing"), "..#\n#..\n").unwrap();
+
+        assert_snapshot(&dir, "existing", "..#\n#..");
+    }
+}